@@ -1,15 +1,23 @@
+//! The BabyJubJub curve in the twisted Edwards form used by circomlib.
+//!
+//! This is the curve `168700 x^2 + y^2 = 1 + 168696 x^2 y^2` over the BN254
+//! scalar field. The generator is circomlib's `Base8`, which generates the
+//! prime-order subgroup.
+
 use ark_ec::{
     models::CurveConfig,
     twisted_edwards::{Affine, MontCurveConfig, Projective, TECurveConfig},
 };
 use ark_ed_on_bn254::{Fq, Fr};
 use ark_ff::MontFp;
-use std::ops::{Add, Mul, Sub};
 
+/// The BabyJubJub curve configuration.
 #[derive(Clone, Default, PartialEq, Eq)]
 pub struct EdwardsConfig;
 
+/// A BabyJubJub point in affine coordinates.
 pub type EdwardsAffine = Affine<EdwardsConfig>;
+/// A BabyJubJub point in projective coordinates.
 pub type EdwardsProjective = Projective<EdwardsConfig>;
 
 impl CurveConfig for EdwardsConfig {
@@ -45,37 +53,9 @@ impl MontCurveConfig for EdwardsConfig {
     type TECurveConfig = EdwardsConfig;
 }
 
+/// The `x` coordinate of the generator (circomlib's `Base8`).
 pub const GENERATOR_X: Fq =
     MontFp!("5299619240641551281634865583518297030282874472190772894086521144482721001553");
+/// The `y` coordinate of the generator (circomlib's `Base8`).
 pub const GENERATOR_Y: Fq =
     MontFp!("16950150798460657717958625567821834550301663161624707787222815936182638968203");
-
-pub struct BabyJubJubElement(pub EdwardsProjective);
-
-impl Add for BabyJubJubElement {
-    type Output = Self;
-
-    fn add(self, other: Self) -> Self::Output {
-        let proj_sum = self.0 + other.0;
-        BabyJubJubElement(proj_sum)
-    }
-}
-
-impl Sub for BabyJubJubElement {
-    type Output = Self;
-
-    fn sub(self, other: Self) -> Self::Output {
-        let proj_sum = self.0 - other.0;
-        BabyJubJubElement(proj_sum)
-    }
-}
-
-impl Mul<Fr> for BabyJubJubElement {
-    type Output = Self;
-
-    fn mul(self, scalar: Fr) -> Self::Output {
-        let proj_self: EdwardsProjective = self.0.into();
-        let proj_result = proj_self.mul(&scalar);
-        BabyJubJubElement(proj_result)
-    }
-}
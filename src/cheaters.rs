@@ -0,0 +1,185 @@
+//! Aggregation that identifies every misbehaving signer.
+//!
+//! `frost_core::frost::aggregate` stops at the first invalid
+//! [`SignatureShare`] and reports a single culprit. [`aggregate`] here checks
+//! every share when the aggregate signature is invalid, and returns an
+//! [`AggregateError::Cheaters`] with all the faulty signers and why each was
+//! rejected, so that the coordinator can exclude them all in one retry.
+
+use std::collections::{BTreeMap, HashMap};
+
+use frost_core::{
+    challenge,
+    frost::{
+        compute_binding_factor_list, compute_group_commitment, derive_interpolating_value,
+        keys::PublicKeyPackage, round2::SignatureShare, Identifier, SigningPackage,
+    },
+    Ciphersuite, Error, Signature,
+};
+
+/// Why a signature share was rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShareFault {
+    /// The signer is not in the [`PublicKeyPackage`].
+    UnknownSigner,
+    /// The signer sent a share without a commitment in the
+    /// [`SigningPackage`].
+    MissingCommitment,
+    /// The signer has a commitment in the [`SigningPackage`] but sent no
+    /// share.
+    MissingShare,
+    /// The share does not verify against the signer's commitment and
+    /// verifying share.
+    InvalidShare,
+}
+
+impl core::fmt::Display for ShareFault {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ShareFault::UnknownSigner => write!(f, "Unknown signer."),
+            ShareFault::MissingCommitment => write!(f, "Missing signing commitment."),
+            ShareFault::MissingShare => write!(f, "Missing signature share."),
+            ShareFault::InvalidShare => write!(f, "Invalid signature share."),
+        }
+    }
+}
+
+/// An error when aggregating signature shares.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AggregateError<C: Ciphersuite> {
+    /// Some signers misbehaved, with the reason for each of them.
+    Cheaters(BTreeMap<Identifier<C>, ShareFault>),
+    /// Any other error.
+    Frost(Error<C>),
+}
+
+impl<C: Ciphersuite> AggregateError<C> {
+    /// Returns the identifiers of all the signers that misbehaved.
+    pub fn culprits(&self) -> Vec<Identifier<C>> {
+        match self {
+            AggregateError::Cheaters(faults) => faults.keys().copied().collect(),
+            AggregateError::Frost(e) => e.culprit().into_iter().collect(),
+        }
+    }
+}
+
+impl<C: Ciphersuite> core::fmt::Display for AggregateError<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AggregateError::Cheaters(faults) => {
+                write!(f, "Invalid signature shares from {} signers:", faults.len())?;
+                for (identifier, fault) in faults {
+                    write!(f, " {identifier:?}: {fault}")?;
+                }
+                Ok(())
+            }
+            AggregateError::Frost(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<C: Ciphersuite> std::error::Error for AggregateError<C> {}
+
+impl<C: Ciphersuite> From<Error<C>> for AggregateError<C> {
+    fn from(e: Error<C>) -> Self {
+        AggregateError::Frost(e)
+    }
+}
+
+/// Finds the signers whose share is missing, unexpected or invalid.
+///
+/// Returns an empty map if every signer of `signing_package` sent a valid
+/// share.
+pub fn identify_cheaters<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &HashMap<Identifier<C>, SignatureShare<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+) -> Result<BTreeMap<Identifier<C>, ShareFault>, Error<C>> {
+    let mut faults = BTreeMap::new();
+    for identifier in signature_shares.keys() {
+        if !signing_package
+            .signing_commitments()
+            .contains_key(identifier)
+        {
+            faults.insert(*identifier, ShareFault::MissingCommitment);
+        }
+    }
+
+    let binding_factor_list =
+        compute_binding_factor_list(signing_package, pubkeys.group_public(), &[]);
+    let group_commitment = compute_group_commitment(signing_package, &binding_factor_list)?;
+    let challenge = challenge::<C>(
+        &group_commitment.to_element(),
+        &pubkeys.group_public().to_element(),
+        signing_package.message(),
+    );
+
+    for (identifier, commitments) in signing_package.signing_commitments() {
+        let Some(verifying_share) = pubkeys.signer_pubkeys().get(identifier) else {
+            faults.insert(*identifier, ShareFault::UnknownSigner);
+            continue;
+        };
+        let Some(signature_share) = signature_shares.get(identifier) else {
+            faults.insert(*identifier, ShareFault::MissingShare);
+            continue;
+        };
+
+        let lambda_i = derive_interpolating_value(identifier, signing_package)?;
+        let binding_factor = binding_factor_list
+            .get(identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+        let group_commitment_share = commitments.to_group_commitment_share(binding_factor);
+        if signature_share
+            .verify(
+                *identifier,
+                &group_commitment_share,
+                verifying_share,
+                lambda_i,
+                &challenge,
+            )
+            .is_err()
+        {
+            faults.insert(*identifier, ShareFault::InvalidShare);
+        }
+    }
+
+    Ok(faults)
+}
+
+/// Aggregates the signature shares into a signature to publish, like
+/// `frost_core::frost::aggregate`.
+///
+/// If the shares do not match `signing_package`, or the signature is
+/// invalid, every share is checked and the error lists all the faulty
+/// signers.
+pub fn aggregate<C: Ciphersuite>(
+    signing_package: &SigningPackage<C>,
+    signature_shares: &HashMap<Identifier<C>, SignatureShare<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+) -> Result<Signature<C>, AggregateError<C>> {
+    let matches = signing_package.signing_commitments().len() == signature_shares.len()
+        && signing_package
+            .signing_commitments()
+            .keys()
+            .all(|identifier| {
+                signature_shares.contains_key(identifier)
+                    && pubkeys.signer_pubkeys().contains_key(identifier)
+            });
+
+    // As in frost-core, shares are only checked one by one if the signature
+    // is invalid, which should be rare.
+    if matches {
+        match frost_core::frost::aggregate(signing_package, signature_shares, pubkeys) {
+            Ok(signature) => return Ok(signature),
+            Err(Error::InvalidSignatureShare { .. }) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    let faults = identify_cheaters(signing_package, signature_shares, pubkeys)?;
+    if faults.is_empty() {
+        // Unreachable unless the shares verify but the signature does not.
+        return Err(Error::InvalidSignature.into());
+    }
+    Err(AggregateError::Cheaters(faults))
+}
@@ -0,0 +1,232 @@
+//! The FROST(babyjubjub, EdDSAPoseidon) ciphersuite.
+//!
+//! Signatures produced with this ciphersuite are accepted by circomlib's
+//! `EdDSAPoseidonVerifier` template and by circomlibjs' `verifyPoseidon`. Both
+//! check
+//!
+//! ```text
+//! S * B8 == R8 + (8 * Poseidon(R8.x, R8.y, A.x, A.y, M)) * A
+//! ```
+//!
+//! where `B8` is the BabyJubJub base point, `A` the group verifying key,
+//! `(R8, S)` the signature and `M` the message as a single base field element.
+//! FROST signs byte strings, so the message must be the 32-byte little-endian
+//! encoding of `M` as returned by [`field_to_message`].
+//!
+//! The remaining hashes (binding factors, nonces, DKG proofs and identifiers)
+//! are never checked by the circuit and reuse the Poseidon construction of
+//! [`BabyJubJubPoseidon`](crate::BabyJubJubPoseidon) with their own domain
+//! separation.
+//!
+//! [`witness_input`] and [`prover_toml`] turn a signature into the inputs of
+//! the circom and Noir verifiers.
+
+use ark_ec::{twisted_edwards::Affine, AffineRepr};
+use ark_ed_on_bn254::{Fq, Fr};
+use ark_ff::{BigInteger, PrimeField, Zero};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+use crate::babyjubjub::EdwardsConfig;
+use crate::poseidon::{decode_point, field_to_scalar, hash_to_array, hash_to_scalar, poseidon};
+use crate::{
+    babyjubjub_ciphersuite, frost_api, BabyJubJubGroup, BabyJubJubHash, BabyJubJubScalarField,
+    Field, Group,
+};
+
+/// Context string for the FROST(babyjubjub, EdDSAPoseidon) ciphersuite.
+const CONTEXT_STRING: &str = "FROST-babyjubjub-EdDSAPoseidon-v1";
+
+/// Converts a message into the base field element signed by circomlib.
+///
+/// The bytes are read as a little-endian integer and reduced modulo the field
+/// order, so only the canonical encodings returned by [`field_to_message`]
+/// map one-to-one onto field elements.
+pub fn message_to_field(msg: &[u8]) -> Fq {
+    Fq::from_le_bytes_mod_order(msg)
+}
+
+/// Encodes a base field element as the message to sign with FROST.
+pub fn field_to_message(msg: Fq) -> [u8; 32] {
+    let bytes = msg.into_bigint().to_bytes_le();
+    let mut output = [0u8; 32];
+    output.copy_from_slice(&bytes);
+    output
+}
+
+/// Computes the circomlib EdDSA-Poseidon challenge `8 * Poseidon(R8, A, M)`.
+fn challenge(R: &Affine<EdwardsConfig>, verifying_key: &Affine<EdwardsConfig>, msg: Fq) -> Fr {
+    let hm = poseidon(&[R.x, R.y, verifying_key.x, verifying_key.y, msg]);
+    Fr::from(8u64) * field_to_scalar(hm)
+}
+
+/// Verifies `signature` over the field element `msg` exactly as circomlib's
+/// `EdDSAPoseidonVerifier` does.
+///
+/// This recomputes the circuit equation directly instead of going through
+/// [`VerifyingKey::verify`], and additionally rejects verifying keys of small
+/// order, which the circuit refuses by checking that `8 * A` has a nonzero
+/// `x` coordinate.
+pub fn verify_circomlib(
+    msg: Fq,
+    signature: &Signature,
+    verifying_key: &VerifyingKey,
+) -> Result<(), Error> {
+    let A = decode_point(&verifying_key.serialize()).ok_or(Error::MalformedVerifyingKey)?;
+    if A.mul_by_cofactor().x.is_zero() {
+        return Err(Error::MalformedVerifyingKey);
+    }
+
+    let bytes = signature.serialize();
+    let R8 = decode_point(&bytes[..32]).ok_or(Error::MalformedSignature)?;
+    let mut S = [0u8; 32];
+    S.copy_from_slice(&bytes[32..]);
+    let S = BabyJubJubScalarField::deserialize(&S).map_err(|_| Error::MalformedSignature)?;
+
+    let left = BabyJubJubGroup::generator() * S;
+    let right = R8.into_group() + A * challenge(&R8, &A, msg);
+    if left == right {
+        Ok(())
+    } else {
+        Err(Error::InvalidSignature)
+    }
+}
+
+/// The input signals of circomlib's `EdDSAPoseidonVerifier` template, as the
+/// decimal strings expected in a snarkjs `input.json`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+pub struct WitnessInput {
+    /// Always `1`; the template skips verification when it is `0`.
+    pub enabled: String,
+    /// The `x` coordinate of the verifying key.
+    pub Ax: String,
+    /// The `y` coordinate of the verifying key.
+    pub Ay: String,
+    /// The signature response.
+    pub S: String,
+    /// The `x` coordinate of the signature commitment.
+    pub R8x: String,
+    /// The `y` coordinate of the signature commitment.
+    pub R8y: String,
+    /// The message as a base field element.
+    pub M: String,
+}
+
+/// Checks `signature` with [`verify_circomlib`] and returns the verification
+/// key, the signature commitment and response, and the message as circuit
+/// values.
+fn circuit_values(
+    msg: &[u8],
+    signature: &Signature,
+    verifying_key: &VerifyingKey,
+) -> Result<(Affine<EdwardsConfig>, Affine<EdwardsConfig>, Fr, Fq), Error> {
+    let M = message_to_field(msg);
+    verify_circomlib(M, signature, verifying_key)?;
+
+    let A = decode_point(&verifying_key.serialize()).ok_or(Error::MalformedVerifyingKey)?;
+    let bytes = signature.serialize();
+    let R8 = decode_point(&bytes[..32]).ok_or(Error::MalformedSignature)?;
+    let S = Fr::from_le_bytes_mod_order(&bytes[32..]);
+    Ok((A, R8, S, M))
+}
+
+/// Builds the witness input proving that `signature` is valid for `msg`
+/// under `verifying_key`.
+///
+/// `msg` is the message signed with FROST and is converted with
+/// [`message_to_field`]. The signature is checked with [`verify_circomlib`]
+/// first, so that an invalid signature is reported here instead of as an
+/// unsatisfied constraint during witness generation.
+pub fn witness_input(
+    msg: &[u8],
+    signature: &Signature,
+    verifying_key: &VerifyingKey,
+) -> Result<WitnessInput, Error> {
+    let (A, R8, S, M) = circuit_values(msg, signature, verifying_key)?;
+
+    let decimal = |value: Fq| value.into_bigint().to_string();
+    Ok(WitnessInput {
+        enabled: "1".to_owned(),
+        Ax: decimal(A.x),
+        Ay: decimal(A.y),
+        S: S.into_bigint().to_string(),
+        R8x: decimal(R8.x),
+        R8y: decimal(R8.y),
+        M: decimal(M),
+    })
+}
+
+/// Builds the `Prover.toml` entries for Noir's
+/// `std::eddsa::eddsa_poseidon_verify`, which checks the same equation as
+/// circomlib.
+///
+/// The entries are named after the parameters of that function
+/// (`pub_key_x`, `pub_key_y`, `signature_s`, `signature_r8_x`,
+/// `signature_r8_y` and `message`) and hold `0x`-prefixed big-endian hex
+/// `Field` values, as written by `nargo`. As with [`witness_input`], `msg` is
+/// the message signed with FROST and the signature is checked first.
+pub fn prover_toml(
+    msg: &[u8],
+    signature: &Signature,
+    verifying_key: &VerifyingKey,
+) -> Result<String, Error> {
+    let (A, R8, S, M) = circuit_values(msg, signature, verifying_key)?;
+
+    let hex = |bytes: Vec<u8>| {
+        let digits: String = bytes.iter().rev().map(|b| format!("{b:02x}")).collect();
+        format!("\"0x{digits}\"")
+    };
+    let entries = [
+        ("pub_key_x", hex(A.x.into_bigint().to_bytes_le())),
+        ("pub_key_y", hex(A.y.into_bigint().to_bytes_le())),
+        ("signature_s", hex(S.into_bigint().to_bytes_le())),
+        ("signature_r8_x", hex(R8.x.into_bigint().to_bytes_le())),
+        ("signature_r8_y", hex(R8.y.into_bigint().to_bytes_le())),
+        ("message", hex(M.into_bigint().to_bytes_le())),
+    ];
+    Ok(entries
+        .iter()
+        .map(|(name, value)| format!("{name} = {value}\n"))
+        .collect())
+}
+
+/// An implementation of the FROST(babyjubjub, EdDSAPoseidon) ciphersuite.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+pub struct BabyJubJubCircomlib;
+
+impl BabyJubJubHash for BabyJubJubCircomlib {
+    const ID: &'static str = "FROST(babyjubjub, EdDSAPoseidon)";
+
+    const CONTEXT_STRING: &'static str = CONTEXT_STRING;
+
+    fn hash_to_scalar(domain: &[u8], msg: &[u8]) -> Fr {
+        hash_to_scalar(domain, msg)
+    }
+
+    fn hash_to_array(domain: &[u8], msg: &[u8]) -> [u8; 32] {
+        hash_to_array(domain, msg)
+    }
+
+    /// frost-core always calls this with `R || A || msg`; the result is the
+    /// circomlib challenge over the decoded points and [`message_to_field`].
+    /// Any other input is hashed as plain bytes.
+    fn challenge(m: &[u8]) -> Fr {
+        let points = m
+            .get(..32)
+            .and_then(decode_point)
+            .zip(m.get(32..64).and_then(decode_point));
+        match points {
+            Some((R, A)) => challenge(&R, &A, message_to_field(&m[64..])),
+            None => hash_to_scalar((CONTEXT_STRING.to_owned() + "chal").as_bytes(), m),
+        }
+    }
+}
+
+babyjubjub_ciphersuite!(BabyJubJubCircomlib);
+
+frost_api!(BabyJubJubCircomlib, "FROST(babyjubjub, EdDSAPoseidon)");
@@ -0,0 +1,202 @@
+//! Decimal representation of points and scalars for circuit inputs.
+//!
+//! Circuit tooling (circom, snarkjs, Noir) expects field elements as decimal
+//! strings rather than the 32-byte hex encoding used by the `serde` feature.
+//! The [`Decimal`] trait converts [`VerifyingKey`], [`VerifyingShare`],
+//! [`SigningCommitments`] and [`Signature`] of the BabyJubJub ciphersuites to
+//! and from structs of decimal strings: points as their affine `x` and `y`
+//! base field coordinates and scalars as integers modulo the group order.
+//!
+//! The module itself is a serde adapter, so a field can be written in this
+//! format with `#[serde(with = "frost_bjj::decimal")]`.
+//!
+//! Parsing only accepts canonical decimals (no sign, leading zeros or values
+//! larger than the modulus) and checks that points are on the curve, in the
+//! prime-order subgroup and not the identity.
+
+use ark_ec::twisted_edwards::Affine;
+use ark_ff::PrimeField;
+use frost_core::{
+    frost::{keys::VerifyingShare, round1::NonceCommitment, round1::SigningCommitments},
+    serde::{self, de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer},
+    Ciphersuite, Error, Signature, VerifyingKey,
+};
+
+use crate::babyjubjub::{EdwardsAffine, EdwardsConfig};
+use crate::poseidon::decode_point;
+use crate::{BabyJubJubGroup, BabyJubJubScalarField, Field, Fq, Fr, Group, GroupError};
+
+/// A point as the decimal strings of its affine coordinates.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "self::serde")]
+pub struct DecimalPoint {
+    /// The `x` coordinate.
+    pub x: String,
+    /// The `y` coordinate.
+    pub y: String,
+}
+
+/// A signature as the decimal strings of `R.x`, `R.y` and `s`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "self::serde")]
+pub struct DecimalSignature {
+    /// The commitment `R`.
+    pub R: DecimalPoint,
+    /// The response `s`.
+    pub s: String,
+}
+
+/// Signing commitments as their hiding and binding points.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "self::serde")]
+pub struct DecimalSigningCommitments {
+    /// The hiding nonce commitment.
+    pub hiding: DecimalPoint,
+    /// The binding nonce commitment.
+    pub binding: DecimalPoint,
+}
+
+/// Types with a decimal field-element representation.
+pub trait Decimal: Sized {
+    /// The decimal representation.
+    type Repr: Serialize + DeserializeOwned;
+
+    /// The error returned when parsing fails.
+    type Error: core::fmt::Display;
+
+    /// Converts `self` to its decimal representation.
+    fn to_decimal(&self) -> Self::Repr;
+
+    /// Parses and validates a decimal representation.
+    fn from_decimal(repr: &Self::Repr) -> Result<Self, Self::Error>;
+}
+
+/// Parses a canonical decimal field element.
+fn parse<F: PrimeField>(s: &str) -> Option<F> {
+    let value = F::from_str(s).ok()?;
+    // `from_str` silently reduces modulo the field order.
+    (value.into_bigint().to_string() == s).then_some(value)
+}
+
+impl DecimalPoint {
+    fn new(point: &EdwardsAffine) -> Self {
+        DecimalPoint {
+            x: point.x.into_bigint().to_string(),
+            y: point.y.into_bigint().to_string(),
+        }
+    }
+
+    /// Returns the default serialization of the point, which still has to be
+    /// deserialized to check that it is in the prime-order subgroup.
+    fn to_bytes(&self) -> Result<[u8; 32], GroupError> {
+        let x = parse::<Fq>(&self.x).ok_or(GroupError::MalformedElement)?;
+        let y = parse::<Fq>(&self.y).ok_or(GroupError::MalformedElement)?;
+        let point = Affine::<EdwardsConfig>::new_unchecked(x, y);
+        if !point.is_on_curve() {
+            return Err(GroupError::MalformedElement);
+        }
+        Ok(BabyJubJubGroup::serialize(&point.into()))
+    }
+}
+
+impl From<[u8; 32]> for DecimalPoint {
+    /// Converts the default serialization of a group element.
+    fn from(bytes: [u8; 32]) -> Self {
+        DecimalPoint::new(&decode_point(&bytes).expect("group elements are valid points"))
+    }
+}
+
+impl<C> Decimal for VerifyingKey<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+{
+    type Repr = DecimalPoint;
+    type Error = Error<C>;
+
+    fn to_decimal(&self) -> DecimalPoint {
+        self.serialize().into()
+    }
+
+    fn from_decimal(repr: &DecimalPoint) -> Result<Self, Error<C>> {
+        let bytes = repr.to_bytes().map_err(|_| Error::MalformedVerifyingKey)?;
+        Self::deserialize(bytes)
+    }
+}
+
+impl<C> Decimal for VerifyingShare<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+{
+    type Repr = DecimalPoint;
+    type Error = Error<C>;
+
+    fn to_decimal(&self) -> DecimalPoint {
+        self.serialize().into()
+    }
+
+    fn from_decimal(repr: &DecimalPoint) -> Result<Self, Error<C>> {
+        Self::deserialize(repr.to_bytes()?)
+    }
+}
+
+impl<C> Decimal for SigningCommitments<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+{
+    type Repr = DecimalSigningCommitments;
+    type Error = Error<C>;
+
+    fn to_decimal(&self) -> DecimalSigningCommitments {
+        DecimalSigningCommitments {
+            hiding: self.hiding().serialize().into(),
+            binding: self.binding().serialize().into(),
+        }
+    }
+
+    fn from_decimal(repr: &DecimalSigningCommitments) -> Result<Self, Error<C>> {
+        Ok(SigningCommitments::new(
+            NonceCommitment::deserialize(repr.hiding.to_bytes()?)?,
+            NonceCommitment::deserialize(repr.binding.to_bytes()?)?,
+        ))
+    }
+}
+
+impl<C> Decimal for Signature<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup, SignatureSerialization = [u8; 64]>,
+{
+    type Repr = DecimalSignature;
+    type Error = Error<C>;
+
+    fn to_decimal(&self) -> DecimalSignature {
+        let bytes = self.serialize();
+        let mut R = [0u8; 32];
+        R.copy_from_slice(&bytes[..32]);
+        let s = Fr::from_le_bytes_mod_order(&bytes[32..]);
+        DecimalSignature {
+            R: R.into(),
+            s: s.into_bigint().to_string(),
+        }
+    }
+
+    fn from_decimal(repr: &DecimalSignature) -> Result<Self, Error<C>> {
+        let R = repr.R.to_bytes().map_err(|_| Error::MalformedSignature)?;
+        let s = parse::<Fr>(&repr.s).ok_or(Error::MalformedSignature)?;
+
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&R);
+        bytes[32..].copy_from_slice(&BabyJubJubScalarField::serialize(&s));
+        Self::deserialize(bytes)
+    }
+}
+
+/// Serializes `value` in its decimal representation.
+pub fn serialize<T: Decimal, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    value.to_decimal().serialize(serializer)
+}
+
+/// Deserializes a value from its decimal representation.
+pub fn deserialize<'de, T: Decimal, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let repr = T::Repr::deserialize(deserializer)?;
+    T::from_decimal(&repr).map_err(serde::de::Error::custom)
+}
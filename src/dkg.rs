@@ -0,0 +1,609 @@
+//! Distributed key generation with a complaint phase and identifiable abort.
+//!
+//! This is the DKG of `frost_core::frost::keys::dkg` with the complaint phase
+//! of the Pedersen and GJKR DKGs, so that a participant sending an invalid
+//! share is identified by everyone and the DKG still completes among the
+//! others:
+//!
+//! - every participant calls [`part1`] and broadcasts its
+//!   [`round1::Package`];
+//! - every participant calls [`part2`] and sends each [`round2::Package`]
+//!   to its recipient over a confidential and authenticated channel. A
+//!   participant whose proof of knowledge is invalid is disqualified;
+//! - every participant calls [`complain`] with the packages it received and
+//!   broadcasts the resulting [`Complaint`]s, possibly none;
+//! - every accused participant calls [`justify`] and broadcasts a
+//!   [`Justification`] revealing the share it sent to its accuser;
+//! - every participant calls [`part3`] with all the broadcast complaints and
+//!   justifications, which returns its [`KeyPackage`], the
+//!   [`PublicKeyPackage`] and the disqualified participants.
+//!
+//! A participant is disqualified if a complaint against it is not answered
+//! by a justification whose share matches its commitment. Since the
+//! commitments, complaints and justifications are public, anyone can compute
+//! the disqualified set with [`disqualified`]. The group key is the sum of the
+//! secrets of the qualified participants, and the disqualified ones get no
+//! verifying share.
+//!
+//! The round 1 and round 2 packages are those of frost-core, so the broadcast
+//! channel must be the same as for its DKG: every participant must receive
+//! the same round 1 packages, complaints and justifications. The
+//! [`encrypted`] module runs the same DKG with encrypted round 2 packages,
+//! so that all the messages can go over a single broadcast channel.
+
+use std::collections::{BTreeSet, HashMap};
+
+use frost_core::{
+    frost::{
+        keys::{
+            KeyPackage, PublicKeyPackage, SigningShare, VerifiableSecretSharingCommitment,
+            VerifyingShare,
+        },
+        Identifier,
+    },
+    Ciphersuite, Element, Error, Field, Group, Scalar, Signature, VerifyingKey,
+};
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+use crate::polynomial::{
+    commit, commitment_elements, evaluate_commitment, evaluate_polynomial, random_coefficients,
+    validate_num_of_signers,
+};
+
+pub mod encrypted;
+
+/// DKG round 1 structures.
+pub mod round1 {
+    use super::*;
+
+    /// The package that must be broadcast by each participant to all other
+    /// participants in round 1, the same as in frost-core's DKG.
+    pub use frost_core::frost::keys::dkg::round1::Package;
+
+    /// The secret package that must be kept in memory by the participant
+    /// between [`part1`] and [`part2`].
+    ///
+    /// # Security
+    ///
+    /// This package MUST NOT be sent to other participants!
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct SecretPackage<C: Ciphersuite> {
+        pub(super) identifier: Identifier<C>,
+        pub(super) coefficients: Vec<Scalar<C>>,
+        pub(super) commitment: VerifiableSecretSharingCommitment<C>,
+        pub(super) min_signers: u16,
+        pub(super) max_signers: u16,
+    }
+
+    impl<C: Ciphersuite> core::fmt::Debug for SecretPackage<C> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("SecretPackage")
+                .field("identifier", &self.identifier)
+                .field("coefficients", &"<redacted>")
+                .field("commitment", &self.commitment)
+                .field("min_signers", &self.min_signers)
+                .field("max_signers", &self.max_signers)
+                .finish()
+        }
+    }
+}
+
+/// DKG round 2 structures.
+pub mod round2 {
+    use super::*;
+
+    /// A package that must be sent by each participant to one other
+    /// participant in round 2, the same as in frost-core's DKG.
+    ///
+    /// # Security
+    ///
+    /// The package must be sent on a confidential and authenticated channel,
+    /// e.g. with [`encrypted`].
+    pub use frost_core::frost::keys::dkg::round2::Package;
+
+    /// The secret package that must be kept in memory by the participant
+    /// between [`part2`] and [`part3`].
+    ///
+    /// It keeps the participant's polynomial to answer complaints with
+    /// [`justify`].
+    ///
+    /// # Security
+    ///
+    /// This package MUST NOT be sent to other participants!
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct SecretPackage<C: Ciphersuite> {
+        pub(super) identifier: Identifier<C>,
+        pub(super) coefficients: Vec<Scalar<C>>,
+        pub(super) commitment: VerifiableSecretSharingCommitment<C>,
+        pub(super) disqualified: BTreeSet<Identifier<C>>,
+        pub(super) min_signers: u16,
+        pub(super) max_signers: u16,
+    }
+
+    impl<C: Ciphersuite> SecretPackage<C> {
+        /// The participants disqualified in [`part2`] for an invalid proof of
+        /// knowledge.
+        pub fn disqualified(&self) -> &BTreeSet<Identifier<C>> {
+            &self.disqualified
+        }
+    }
+
+    impl<C: Ciphersuite> core::fmt::Debug for SecretPackage<C> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("SecretPackage")
+                .field("identifier", &self.identifier)
+                .field("coefficients", &"<redacted>")
+                .field("commitment", &self.commitment)
+                .field("disqualified", &self.disqualified)
+                .field("min_signers", &self.min_signers)
+                .field("max_signers", &self.max_signers)
+                .finish()
+        }
+    }
+}
+
+/// A complaint broadcast by a participant that received an invalid or no
+/// [`round2::Package`] from another participant.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "C: Ciphersuite + serde::Serialize",
+        deserialize = "C: Ciphersuite + serde::Deserialize<'de>"
+    ))
+)]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Complaint<C: Ciphersuite> {
+    accuser: Identifier<C>,
+    accused: Identifier<C>,
+}
+
+impl<C: Ciphersuite> Complaint<C> {
+    /// Creates a new [`Complaint`].
+    pub fn new(accuser: Identifier<C>, accused: Identifier<C>) -> Self {
+        Self { accuser, accused }
+    }
+
+    /// The participant that did not receive a valid share.
+    pub fn accuser(&self) -> &Identifier<C> {
+        &self.accuser
+    }
+
+    /// The participant that sent the share.
+    pub fn accused(&self) -> &Identifier<C> {
+        &self.accused
+    }
+}
+
+/// The answer of an accused participant to a [`Complaint`], which reveals
+/// the share it sent to the accuser.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "C: Ciphersuite + serde::Serialize",
+        deserialize = "C: Ciphersuite + serde::Deserialize<'de>"
+    ))
+)]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Justification<C: Ciphersuite> {
+    accuser: Identifier<C>,
+    accused: Identifier<C>,
+    secret_share: SigningShare<C>,
+}
+
+impl<C: Ciphersuite> Justification<C> {
+    /// Creates a new [`Justification`].
+    pub fn new(
+        accuser: Identifier<C>,
+        accused: Identifier<C>,
+        secret_share: SigningShare<C>,
+    ) -> Self {
+        Self {
+            accuser,
+            accused,
+            secret_share,
+        }
+    }
+
+    /// The participant that complained.
+    pub fn accuser(&self) -> &Identifier<C> {
+        &self.accuser
+    }
+
+    /// The participant answering the complaint.
+    pub fn accused(&self) -> &Identifier<C> {
+        &self.accused
+    }
+
+    /// The share sent by the accused participant to the accuser.
+    pub fn secret_share(&self) -> &SigningShare<C> {
+        &self.secret_share
+    }
+}
+
+/// Computes the challenge of the proof of knowledge like frost-core's DKG.
+fn challenge<C: Ciphersuite>(
+    identifier: &Identifier<C>,
+    verifying_key: &Element<C>,
+    R: &Element<C>,
+) -> Result<Scalar<C>, Error<C>> {
+    let mut preimage = vec![];
+    preimage.extend_from_slice(identifier.serialize().as_ref());
+    preimage.extend_from_slice(<C::Group>::serialize(verifying_key).as_ref());
+    preimage.extend_from_slice(<C::Group>::serialize(R).as_ref());
+    C::HDKG(&preimage).ok_or(Error::DKGNotSupported)
+}
+
+/// Returns the `R` and `z` components of `signature`, or `None` if they are
+/// invalid.
+fn signature_components<C: Ciphersuite>(
+    signature: &Signature<C>,
+) -> Option<(Element<C>, Scalar<C>)> {
+    let bytes = signature.serialize();
+    let element_len = <C::Group>::serialize(&<C::Group>::generator())
+        .as_ref()
+        .len();
+    let (R, z) = bytes.as_ref().split_at(element_len);
+    let R = <C::Group>::deserialize(&R.to_vec().try_into().ok()?).ok()?;
+    let z = <<C::Group as Group>::Field>::deserialize(&z.to_vec().try_into().ok()?).ok()?;
+    Some((R, z))
+}
+
+/// Checks the proof of knowledge of the secret of `identifier` in `package`.
+fn verify_proof_of_knowledge<C: Ciphersuite>(
+    identifier: &Identifier<C>,
+    package: &round1::Package<C>,
+) -> Result<bool, Error<C>> {
+    let Some((R, z)) = signature_components(package.proof_of_knowledge()) else {
+        return Ok(false);
+    };
+    let Some(&phi) = commitment_elements(package.commitment()).first() else {
+        return Err(Error::IncorrectNumberOfCommitments);
+    };
+    let c = challenge(identifier, &phi, &R)?;
+    Ok(R == <C::Group>::generator() * z - phi * c)
+}
+
+/// Checks that `secret_share` is the evaluation at `identifier` of the
+/// polynomial committed to by `commitment`.
+fn verify_share<C: Ciphersuite>(
+    identifier: &Identifier<C>,
+    secret_share: &SigningShare<C>,
+    commitment: &VerifiableSecretSharingCommitment<C>,
+) -> bool {
+    let elements = commitment_elements(commitment);
+    let Some((constant, coefficients)) = elements.split_first() else {
+        return false;
+    };
+    <C::Group>::generator() * secret_share.to_scalar()
+        == evaluate_commitment(identifier, *constant, coefficients)
+}
+
+/// Performs the first part of the DKG for the given participant.
+///
+/// It returns the [`round1::SecretPackage`] that must be kept in memory for
+/// [`part2`], and the [`round1::Package`] that must be broadcast.
+pub fn part1<C, R>(
+    identifier: Identifier<C>,
+    max_signers: u16,
+    min_signers: u16,
+    mut rng: R,
+) -> Result<(round1::SecretPackage<C>, round1::Package<C>), Error<C>>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    validate_num_of_signers::<C>(min_signers, max_signers)?;
+
+    let coefficients = random_coefficients::<C, R>(min_signers as usize, &mut rng);
+    let commitment = commit(&coefficients)?;
+
+    let k = <<C::Group as Group>::Field>::random(&mut rng);
+    let R = <C::Group>::generator() * k;
+    let c = challenge(
+        &identifier,
+        &(<C::Group>::generator() * coefficients[0]),
+        &R,
+    )?;
+    let proof_of_knowledge = Signature::new(R, k + coefficients[0] * c);
+
+    Ok((
+        round1::SecretPackage {
+            identifier,
+            coefficients,
+            commitment: commitment.clone(),
+            min_signers,
+            max_signers,
+        },
+        round1::Package::new(commitment, proof_of_knowledge),
+    ))
+}
+
+/// Performs the second part of the DKG, given the [`round1::Package`]s
+/// received from the other participants.
+///
+/// Participants with an invalid proof of knowledge or commitment are
+/// disqualified rather than aborting the DKG, and get no
+/// [`round2::Package`]. It returns the [`round2::SecretPackage`] to keep for
+/// [`part3`] and the [`round2::Package`]s to send to the other participants.
+#[allow(clippy::type_complexity)]
+pub fn part2<C: Ciphersuite>(
+    secret_package: round1::SecretPackage<C>,
+    round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+) -> Result<
+    (
+        round2::SecretPackage<C>,
+        HashMap<Identifier<C>, round2::Package<C>>,
+    ),
+    Error<C>,
+> {
+    if round1_packages.len() != secret_package.max_signers as usize - 1 {
+        return Err(Error::IncorrectNumberOfPackages);
+    }
+
+    let (constant, coefficients) = (
+        secret_package.coefficients[0],
+        &secret_package.coefficients[1..],
+    );
+    let mut disqualified = BTreeSet::new();
+    let mut round2_packages = HashMap::new();
+    for (identifier, package) in round1_packages {
+        if *identifier == secret_package.identifier {
+            return Err(Error::IncorrectPackage);
+        }
+        if package.commitment().serialize().len() != secret_package.min_signers as usize
+            || !verify_proof_of_knowledge(identifier, package)?
+        {
+            disqualified.insert(*identifier);
+            continue;
+        }
+        let value = evaluate_polynomial(identifier, constant, coefficients);
+        round2_packages.insert(*identifier, round2::Package::new(SigningShare::new(value)));
+    }
+
+    Ok((
+        round2::SecretPackage {
+            identifier: secret_package.identifier,
+            coefficients: secret_package.coefficients,
+            commitment: secret_package.commitment,
+            disqualified,
+            min_signers: secret_package.min_signers,
+            max_signers: secret_package.max_signers,
+        },
+        round2_packages,
+    ))
+}
+
+/// Checks the [`round2::Package`]s received from the other participants, and
+/// returns a [`Complaint`] against every qualified participant whose package
+/// is missing or does not match its commitment.
+///
+/// `round1_packages` must be the same used in [`part2`]. The complaints must
+/// be broadcast to all the participants.
+pub fn complain<C: Ciphersuite>(
+    round2_secret_package: &round2::SecretPackage<C>,
+    round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+    round2_packages: &HashMap<Identifier<C>, round2::Package<C>>,
+) -> Vec<Complaint<C>> {
+    let identifier = round2_secret_package.identifier;
+    let mut complaints: Vec<_> = round1_packages
+        .iter()
+        .filter(|(sender, _)| !round2_secret_package.disqualified.contains(sender))
+        .filter(|(sender, package)| {
+            !round2_packages.get(sender).is_some_and(|round2_package| {
+                verify_share(
+                    &identifier,
+                    round2_package.secret_share(),
+                    package.commitment(),
+                )
+            })
+        })
+        .map(|(sender, _)| Complaint::new(identifier, *sender))
+        .collect();
+    complaints.sort_by_key(|complaint| complaint.accused);
+    complaints
+}
+
+/// Answers the complaints against the participant holding
+/// `round2_secret_package` by revealing the shares it sent to the accusers.
+///
+/// The justifications must be broadcast to all the participants.
+pub fn justify<C: Ciphersuite>(
+    round2_secret_package: &round2::SecretPackage<C>,
+    complaints: &[Complaint<C>],
+) -> Vec<Justification<C>> {
+    let identifier = round2_secret_package.identifier;
+    let (constant, coefficients) = (
+        round2_secret_package.coefficients[0],
+        &round2_secret_package.coefficients[1..],
+    );
+    complaints
+        .iter()
+        .filter(|complaint| complaint.accused == identifier)
+        .map(|complaint| {
+            let value = evaluate_polynomial(&complaint.accuser, constant, coefficients);
+            Justification::new(complaint.accuser, identifier, SigningShare::new(value))
+        })
+        .collect()
+}
+
+/// Returns the participants whose complaints are not answered by a valid
+/// [`Justification`], given the commitments of all the participants.
+fn unjustified<C: Ciphersuite>(
+    commitments: &HashMap<Identifier<C>, &VerifiableSecretSharingCommitment<C>>,
+    complaints: &[Complaint<C>],
+    justifications: &[Justification<C>],
+) -> BTreeSet<Identifier<C>> {
+    complaints
+        .iter()
+        .filter(|complaint| {
+            commitments.contains_key(&complaint.accuser)
+                && commitments.contains_key(&complaint.accused)
+        })
+        .filter(|complaint| {
+            !justifications.iter().any(|justification| {
+                justification.accuser == complaint.accuser
+                    && justification.accused == complaint.accused
+                    && verify_share(
+                        &justification.accuser,
+                        &justification.secret_share,
+                        commitments[&justification.accused],
+                    )
+            })
+        })
+        .map(|complaint| complaint.accused)
+        .collect()
+}
+
+/// Returns the disqualified participants, given the [`round1::Package`]s of
+/// all the participants and all the broadcast complaints and
+/// justifications.
+///
+/// A participant is disqualified if its proof of knowledge or commitment is
+/// invalid, or if a complaint against it has no valid justification. Anyone
+/// can call it, e.g. to check the result of [`part3`].
+///
+/// It fails with [`Error::InvalidMinSigners`] if `min_signers` is invalid for
+/// the number of participants.
+pub fn disqualified<C: Ciphersuite>(
+    round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+    min_signers: u16,
+    complaints: &[Complaint<C>],
+    justifications: &[Justification<C>],
+) -> Result<BTreeSet<Identifier<C>>, Error<C>> {
+    let max_signers =
+        u16::try_from(round1_packages.len()).map_err(|_| Error::IncorrectNumberOfPackages)?;
+    validate_num_of_signers::<C>(min_signers, max_signers)?;
+    let mut disqualified = BTreeSet::new();
+    for (identifier, package) in round1_packages {
+        if package.commitment().serialize().len() != min_signers as usize
+            || !verify_proof_of_knowledge(identifier, package)?
+        {
+            disqualified.insert(*identifier);
+        }
+    }
+    let commitments = round1_packages
+        .iter()
+        .map(|(identifier, package)| (*identifier, package.commitment()))
+        .collect();
+    disqualified.extend(unjustified(&commitments, complaints, justifications));
+    Ok(disqualified)
+}
+
+/// Performs the third and final part of the DKG, given the
+/// [`round2::Package`]s received from the other participants and all the
+/// broadcast complaints and justifications.
+///
+/// `round1_packages` must be the same used in [`part2`]. The shares of
+/// participants this one complained about are taken from their valid
+/// justifications. It returns the [`KeyPackage`] of the participant, the
+/// [`PublicKeyPackage`] of the qualified participants, and the disqualified
+/// participants.
+///
+/// It fails with [`Error::InvalidSecretShare`] if a qualified participant
+/// sent an invalid share without a complaint from this participant, or if
+/// this participant is disqualified itself, and with
+/// [`Error::InvalidMinSigners`] if fewer than `min_signers` participants are
+/// qualified, since they could never sign.
+#[allow(clippy::type_complexity)]
+pub fn part3<C: Ciphersuite>(
+    round2_secret_package: &round2::SecretPackage<C>,
+    round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+    round2_packages: &HashMap<Identifier<C>, round2::Package<C>>,
+    complaints: &[Complaint<C>],
+    justifications: &[Justification<C>],
+) -> Result<(KeyPackage<C>, PublicKeyPackage<C>, BTreeSet<Identifier<C>>), Error<C>> {
+    let secret_package = round2_secret_package;
+    let identifier = secret_package.identifier;
+    if round1_packages.len() != secret_package.max_signers as usize - 1 {
+        return Err(Error::IncorrectNumberOfPackages);
+    }
+
+    let mut commitments: HashMap<_, _> = round1_packages
+        .iter()
+        .map(|(identifier, package)| (*identifier, package.commitment()))
+        .collect();
+    commitments.insert(identifier, &secret_package.commitment);
+
+    let mut disqualified = secret_package.disqualified.clone();
+    disqualified.extend(unjustified(&commitments, complaints, justifications));
+    if disqualified.contains(&identifier) {
+        return Err(Error::InvalidSecretShare);
+    }
+    let num_qualified = commitments
+        .keys()
+        .filter(|participant| !disqualified.contains(participant))
+        .count();
+    if num_qualified < secret_package.min_signers as usize {
+        return Err(Error::InvalidMinSigners);
+    }
+
+    let mut signing_share = evaluate_polynomial(
+        &identifier,
+        secret_package.coefficients[0],
+        &secret_package.coefficients[1..],
+    );
+    for (sender, commitment) in &commitments {
+        if *sender == identifier || disqualified.contains(sender) {
+            continue;
+        }
+        // Invalid justifications are skipped, like in `unjustified`, so that
+        // a valid one posted along with them is used.
+        let justification = justifications.iter().find(|justification| {
+            justification.accuser == identifier
+                && justification.accused == *sender
+                && verify_share(&identifier, &justification.secret_share, commitment)
+        });
+        let secret_share = match (justification, round2_packages.get(sender)) {
+            (Some(justification), _) => justification.secret_share,
+            (None, Some(package)) => *package.secret_share(),
+            (None, None) => return Err(Error::PackageNotFound),
+        };
+        if !verify_share(&identifier, &secret_share, commitment) {
+            return Err(Error::InvalidSecretShare);
+        }
+        signing_share = signing_share + secret_share.to_scalar();
+    }
+
+    let qualified: Vec<_> = commitments
+        .iter()
+        .filter(|(sender, _)| !disqualified.contains(sender))
+        .map(|(_, commitment)| commitment_elements(commitment))
+        .collect();
+    let group_public = VerifyingKey::new(
+        qualified
+            .iter()
+            .fold(<C::Group>::identity(), |sum, c| sum + c[0]),
+    );
+    let signer_pubkeys = commitments
+        .keys()
+        .filter(|participant| !disqualified.contains(participant))
+        .map(|participant| {
+            let element = qualified.iter().fold(<C::Group>::identity(), |sum, c| {
+                sum + evaluate_commitment(participant, c[0], &c[1..])
+            });
+            (*participant, VerifyingShare::new(element))
+        })
+        .collect();
+
+    let signing_share = SigningShare::new(signing_share);
+    Ok((
+        KeyPackage::new(
+            identifier,
+            signing_share,
+            VerifyingShare::from(signing_share),
+            group_public,
+            secret_package.min_signers,
+        ),
+        PublicKeyPackage::new(signer_pubkeys, group_public),
+        disqualified,
+    ))
+}
@@ -0,0 +1,331 @@
+//! The DKG with complaints over a public broadcast board.
+//!
+//! This wraps the functions of the parent module so that no private channel
+//! is needed: every [`round1::Package`] also carries an encryption key of
+//! its sender, and every [`round2::Package`] is encrypted and authenticated
+//! to its recipient with [`ecies::encrypt_authenticated`]. All the packages,
+//! complaints and justifications can then be posted to the same board, which
+//! must still authenticate who posted each round 1 package and give every
+//! participant the same view.
+//!
+//! A round 2 package that cannot be decrypted is handled like a missing one:
+//! its recipient complains, and the sender reveals the share in a
+//! [`Justification`] or is disqualified.
+
+use std::collections::{BTreeSet, HashMap};
+
+use frost_core::{
+    frost::{
+        keys::{KeyPackage, PublicKeyPackage, SigningShare},
+        Identifier,
+    },
+    Ciphersuite, Error, Group, SigningKey, VerifyingKey,
+};
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+use super::{Complaint, Justification};
+use crate::ecies::{self, Ciphertext};
+
+/// Encrypted DKG round 1 structures.
+pub mod round1 {
+    use super::*;
+
+    /// The package that must be broadcast by each participant to all other
+    /// participants in round 1.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "C: Ciphersuite + serde::Serialize",
+            deserialize = "C: Ciphersuite + serde::Deserialize<'de>"
+        ))
+    )]
+    #[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+    #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+    pub struct Package<C: Ciphersuite> {
+        package: crate::dkg::round1::Package<C>,
+        encryption_key: VerifyingKey<C>,
+    }
+
+    impl<C: Ciphersuite> Package<C> {
+        /// Creates a new [`Package`].
+        pub fn new(
+            package: crate::dkg::round1::Package<C>,
+            encryption_key: VerifyingKey<C>,
+        ) -> Self {
+            Self {
+                package,
+                encryption_key,
+            }
+        }
+
+        /// The commitment and proof of knowledge of the participant.
+        pub fn package(&self) -> &crate::dkg::round1::Package<C> {
+            &self.package
+        }
+
+        /// The key to encrypt the round 2 package of the participant to.
+        pub fn encryption_key(&self) -> &VerifyingKey<C> {
+            &self.encryption_key
+        }
+    }
+
+    /// The secret package that must be kept in memory by the participant
+    /// between [`part1`] and [`part2`].
+    ///
+    /// # Security
+    ///
+    /// This package MUST NOT be sent to other participants!
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct SecretPackage<C: Ciphersuite> {
+        pub(super) secret_package: crate::dkg::round1::SecretPackage<C>,
+        pub(super) decryption_key: SigningKey<C>,
+    }
+}
+
+/// Encrypted DKG round 2 structures.
+pub mod round2 {
+    use super::*;
+
+    /// A package that must be sent by each participant to one other
+    /// participant in round 2. It can be broadcast since only its recipient
+    /// can decrypt it.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(
+            serialize = "C: Ciphersuite + serde::Serialize",
+            deserialize = "C: Ciphersuite + serde::Deserialize<'de>"
+        ))
+    )]
+    #[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+    #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+    pub struct Package<C: Ciphersuite> {
+        ciphertext: Ciphertext<C>,
+    }
+
+    impl<C: Ciphersuite> Package<C> {
+        /// Creates a new [`Package`].
+        pub fn new(ciphertext: Ciphertext<C>) -> Self {
+            Self { ciphertext }
+        }
+
+        /// The encrypted secret share.
+        pub fn ciphertext(&self) -> &Ciphertext<C> {
+            &self.ciphertext
+        }
+    }
+
+    /// The secret package that must be kept in memory by the participant
+    /// between [`part2`] and [`part3`].
+    ///
+    /// # Security
+    ///
+    /// This package MUST NOT be sent to other participants!
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct SecretPackage<C: Ciphersuite> {
+        pub(super) secret_package: crate::dkg::round2::SecretPackage<C>,
+        pub(super) decryption_key: SigningKey<C>,
+    }
+
+    impl<C: Ciphersuite> SecretPackage<C> {
+        /// The participants disqualified in [`part2`] for an invalid proof of
+        /// knowledge or commitment.
+        pub fn disqualified(&self) -> &BTreeSet<Identifier<C>> {
+            self.secret_package.disqualified()
+        }
+    }
+}
+
+/// Returns the packages of the parent module inside `round1_packages`.
+fn inner_packages<C: Ciphersuite>(
+    round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+) -> HashMap<Identifier<C>, crate::dkg::round1::Package<C>> {
+    round1_packages
+        .iter()
+        .map(|(identifier, package)| (*identifier, package.package().clone()))
+        .collect()
+}
+
+/// The associated data of the round 2 package from `sender` to `recipient`.
+fn associated_data<C: Ciphersuite>(sender: &Identifier<C>, recipient: &Identifier<C>) -> Vec<u8> {
+    let mut aad = sender.serialize().as_ref().to_vec();
+    aad.extend_from_slice(recipient.serialize().as_ref());
+    aad
+}
+
+/// Decrypts the round 2 packages received by the participant, leaving out
+/// those that cannot be decrypted.
+fn decrypt<C: Ciphersuite>(
+    round2_secret_package: &round2::SecretPackage<C>,
+    round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+    round2_packages: &HashMap<Identifier<C>, round2::Package<C>>,
+) -> HashMap<Identifier<C>, crate::dkg::round2::Package<C>> {
+    let identifier = round2_secret_package.secret_package.identifier;
+    round2_packages
+        .iter()
+        .filter_map(|(sender, package)| {
+            let plaintext = ecies::decrypt_authenticated(
+                &round2_secret_package.decryption_key,
+                round1_packages.get(sender)?.encryption_key(),
+                &associated_data(sender, &identifier),
+                package.ciphertext(),
+            )
+            .ok()?;
+            let secret_share = SigningShare::deserialize(plaintext.try_into().ok()?).ok()?;
+            Some((*sender, crate::dkg::round2::Package::new(secret_share)))
+        })
+        .collect()
+}
+
+/// Performs the first part of the DKG for the given participant, like
+/// [`super::part1`], and generates its encryption key.
+///
+/// It returns the [`round1::SecretPackage`] that must be kept in memory for
+/// [`part2`], and the [`round1::Package`] that must be broadcast.
+pub fn part1<C, R>(
+    identifier: Identifier<C>,
+    max_signers: u16,
+    min_signers: u16,
+    mut rng: R,
+) -> Result<(round1::SecretPackage<C>, round1::Package<C>), Error<C>>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    let (secret_package, package) = super::part1(identifier, max_signers, min_signers, &mut rng)?;
+    let decryption_key = SigningKey::new(&mut rng);
+    Ok((
+        round1::SecretPackage {
+            secret_package,
+            decryption_key,
+        },
+        round1::Package::new(package, VerifyingKey::from(decryption_key)),
+    ))
+}
+
+/// Performs the second part of the DKG like [`super::part2`], and encrypts
+/// each [`round2::Package`] to its recipient.
+///
+/// Participants with an invalid encryption key are disqualified too. The
+/// returned packages can be broadcast.
+#[allow(clippy::type_complexity)]
+pub fn part2<C, R>(
+    secret_package: round1::SecretPackage<C>,
+    round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+    mut rng: R,
+) -> Result<
+    (
+        round2::SecretPackage<C>,
+        HashMap<Identifier<C>, round2::Package<C>>,
+    ),
+    Error<C>,
+>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    let decryption_key = secret_package.decryption_key;
+    let (mut secret_package, packages) = super::part2(
+        secret_package.secret_package,
+        &inner_packages(round1_packages),
+    )?;
+    let identifier = secret_package.identifier;
+
+    let mut round2_packages = HashMap::new();
+    for (recipient, package) in packages {
+        match ecies::encrypt_authenticated(
+            &decryption_key,
+            round1_packages[&recipient].encryption_key(),
+            &associated_data(&identifier, &recipient),
+            package.secret_share().serialize().as_ref(),
+            &mut rng,
+        ) {
+            Ok(ciphertext) => {
+                round2_packages.insert(recipient, round2::Package::new(ciphertext));
+            }
+            Err(_) => {
+                secret_package.disqualified.insert(recipient);
+            }
+        }
+    }
+
+    Ok((
+        round2::SecretPackage {
+            secret_package,
+            decryption_key,
+        },
+        round2_packages,
+    ))
+}
+
+/// Decrypts and checks the [`round2::Package`]s received from the other
+/// participants like [`super::complain`]. Packages that cannot be decrypted
+/// are complained about as if they were missing.
+pub fn complain<C: Ciphersuite>(
+    round2_secret_package: &round2::SecretPackage<C>,
+    round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+    round2_packages: &HashMap<Identifier<C>, round2::Package<C>>,
+) -> Vec<Complaint<C>> {
+    super::complain(
+        &round2_secret_package.secret_package,
+        &inner_packages(round1_packages),
+        &decrypt(round2_secret_package, round1_packages, round2_packages),
+    )
+}
+
+/// Answers the complaints against the participant holding
+/// `round2_secret_package`, like [`super::justify`].
+pub fn justify<C: Ciphersuite>(
+    round2_secret_package: &round2::SecretPackage<C>,
+    complaints: &[Complaint<C>],
+) -> Vec<Justification<C>> {
+    super::justify(&round2_secret_package.secret_package, complaints)
+}
+
+/// Returns the disqualified participants like [`super::disqualified`],
+/// including those with an invalid encryption key.
+pub fn disqualified<C: Ciphersuite>(
+    round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+    min_signers: u16,
+    complaints: &[Complaint<C>],
+    justifications: &[Justification<C>],
+) -> Result<BTreeSet<Identifier<C>>, Error<C>> {
+    let mut disqualified = super::disqualified(
+        &inner_packages(round1_packages),
+        min_signers,
+        complaints,
+        justifications,
+    )?;
+    disqualified.extend(
+        round1_packages
+            .iter()
+            .filter(|(_, package)| package.encryption_key().to_element() == <C::Group>::identity())
+            .map(|(identifier, _)| *identifier),
+    );
+    Ok(disqualified)
+}
+
+/// Performs the third and final part of the DKG like [`super::part3`], after
+/// decrypting the [`round2::Package`]s received from the other participants.
+#[allow(clippy::type_complexity)]
+pub fn part3<C: Ciphersuite>(
+    round2_secret_package: &round2::SecretPackage<C>,
+    round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+    round2_packages: &HashMap<Identifier<C>, round2::Package<C>>,
+    complaints: &[Complaint<C>],
+    justifications: &[Justification<C>],
+) -> Result<(KeyPackage<C>, PublicKeyPackage<C>, BTreeSet<Identifier<C>>), Error<C>> {
+    super::part3(
+        &round2_secret_package.secret_package,
+        &inner_packages(round1_packages),
+        &decrypt(round2_secret_package, round1_packages, round2_packages),
+        complaints,
+        justifications,
+    )
+}
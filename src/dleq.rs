@@ -0,0 +1,266 @@
+//! Chaum–Pedersen proofs of discrete logarithm equality, and threshold
+//! evaluations of `secret·P` from FROST key shares.
+//!
+//! A [`Proof`] shows that `X = x·G` and `Y = x·H` for the same `x`, without
+//! revealing it. A participant holding a [`KeyPackage`] computes its
+//! [`Share`] `s_i·P` of `secret·P`, proven against its [`VerifyingShare`]
+//! `s_i·G`, and any `min_signers` valid shares are interpolated into
+//! `secret·P`. The threshold primitives of this crate are built on it: the
+//! point `P` is an ElGamal ephemeral key in [`elgamal`](crate::elgamal), and
+//! the public key of another party in [`ecdh`](crate::ecdh). The threshold
+//! [`vrf`](crate::vrf) computes its proofs jointly, with the same challenge.
+//!
+//! The challenge is [`hash_to_scalar`] with the domain
+//! `CONTEXT_STRING || "dleq-" || label` over `ser(H) || ser(X) || ser(Y) ||
+//! ser(A) || ser(B)`, where `A` and `B` are the commitments to the nonce, so
+//! proofs made for one protocol are rejected by the others.
+//!
+//! [`hash_to_scalar`]: crate::BabyJubJubHash::hash_to_scalar
+//! [`VerifyingShare`]: frost_core::frost::keys::VerifyingShare
+
+use std::collections::{BTreeSet, HashMap};
+
+use frost_core::{
+    frost::{
+        compute_lagrange_coefficient,
+        keys::{KeyPackage, PublicKeyPackage},
+        Identifier,
+    },
+    Ciphersuite, Element, Error, Field, Group, Scalar,
+};
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+use crate::{BabyJubJubGroup, BabyJubJubHash};
+
+/// A proof that two elements have the same discrete logarithm to the
+/// generator and to another base.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound = "C: Ciphersuite<Group = BabyJubJubGroup>")
+)]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Proof<C: Ciphersuite<Group = BabyJubJubGroup>> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::scalar"))]
+    challenge: Scalar<C>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::scalar"))]
+    response: Scalar<C>,
+}
+
+impl<C: Ciphersuite<Group = BabyJubJubGroup>> Proof<C> {
+    /// Creates a new [`Proof`].
+    pub fn new(challenge: Scalar<C>, response: Scalar<C>) -> Self {
+        Self {
+            challenge,
+            response,
+        }
+    }
+
+    /// The challenge `c`.
+    pub fn challenge(&self) -> &Scalar<C> {
+        &self.challenge
+    }
+
+    /// The response `z = k + c·x`.
+    pub fn response(&self) -> &Scalar<C> {
+        &self.response
+    }
+}
+
+/// Computes the challenge of a proof over `[H, X, Y, A, B]`.
+pub(crate) fn challenge<C>(label: &str, elements: [&Element<C>; 5]) -> Scalar<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+{
+    let mut preimage = Vec::new();
+    for element in elements {
+        preimage.extend_from_slice(&BabyJubJubGroup::serialize(element));
+    }
+    C::hash_to_scalar(
+        (C::CONTEXT_STRING.to_owned() + "dleq-" + label).as_bytes(),
+        &preimage,
+    )
+}
+
+/// Proves that `secret·G` and `secret·base` have the same discrete
+/// logarithm, for the protocol named `label`.
+pub fn prove<C, R>(label: &str, secret: Scalar<C>, base: &Element<C>, rng: &mut R) -> Proof<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+    R: RngCore + CryptoRng,
+{
+    let k = <<C::Group as Group>::Field>::random(rng);
+    let c = challenge::<C>(
+        label,
+        [
+            base,
+            &(BabyJubJubGroup::generator() * secret),
+            &(*base * secret),
+            &(BabyJubJubGroup::generator() * k),
+            &(*base * k),
+        ],
+    );
+    Proof::new(c, k + c * secret)
+}
+
+/// Verifies a [`Proof`] that `public = x·G` and `result = x·base` for the
+/// same `x`, for the protocol named `label`.
+pub fn verify<C>(
+    label: &str,
+    public: &Element<C>,
+    base: &Element<C>,
+    result: &Element<C>,
+    proof: &Proof<C>,
+) -> bool
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+{
+    let (c, z) = (proof.challenge, proof.response);
+    let A = BabyJubJubGroup::generator() * z - *public * c;
+    let B = *base * z - *result * c;
+    challenge::<C>(label, [base, public, result, &A, &B]) == c
+}
+
+/// A participant's share `s_i·P` of `secret·P`, with a [`Proof`] against
+/// its verifying share.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound = "C: Ciphersuite<Group = BabyJubJubGroup>")
+)]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Share<C: Ciphersuite<Group = BabyJubJubGroup>> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::element"))]
+    element: Element<C>,
+    proof: Proof<C>,
+}
+
+impl<C: Ciphersuite<Group = BabyJubJubGroup>> Share<C> {
+    /// Creates a new [`Share`].
+    pub fn new(element: Element<C>, proof: Proof<C>) -> Self {
+        Self { element, proof }
+    }
+
+    /// The element `s_i·P`.
+    pub fn element(&self) -> &Element<C> {
+        &self.element
+    }
+
+    /// The proof that the element matches the verifying share.
+    pub fn proof(&self) -> &Proof<C> {
+        &self.proof
+    }
+}
+
+/// An error when combining [`Share`]s.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CombineError<C: Ciphersuite> {
+    /// The shares of these participants have an invalid proof, or the
+    /// participants are not in the [`PublicKeyPackage`].
+    InvalidShares(BTreeSet<Identifier<C>>),
+    /// Any other error.
+    Frost(Error<C>),
+}
+
+impl<C: Ciphersuite> CombineError<C> {
+    /// Returns the identifiers of the participants that sent an invalid
+    /// share.
+    pub fn culprits(&self) -> Vec<Identifier<C>> {
+        match self {
+            CombineError::InvalidShares(culprits) => culprits.iter().copied().collect(),
+            CombineError::Frost(e) => e.culprit().into_iter().collect(),
+        }
+    }
+}
+
+impl<C: Ciphersuite> core::fmt::Display for CombineError<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CombineError::InvalidShares(culprits) => {
+                write!(f, "Invalid shares from {culprits:?}.")
+            }
+            CombineError::Frost(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<C: Ciphersuite> std::error::Error for CombineError<C> {}
+
+impl<C: Ciphersuite> From<Error<C>> for CombineError<C> {
+    fn from(e: Error<C>) -> Self {
+        CombineError::Frost(e)
+    }
+}
+
+/// Computes the [`Share`] of `secret·base` of the participant holding
+/// `key_package`.
+pub(crate) fn share<C, R>(
+    label: &str,
+    key_package: &KeyPackage<C>,
+    base: &Element<C>,
+    rng: &mut R,
+) -> Share<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+    R: RngCore + CryptoRng,
+{
+    let secret = key_package.secret_share().to_scalar();
+    Share::new(*base * secret, prove(label, secret, base, rng))
+}
+
+/// Checks every [`Share`] against the verifying shares in `pubkeys` and
+/// interpolates `secret·base` from at least `min_signers` of them.
+///
+/// Fails with [`CombineError::InvalidShares`] naming every participant whose
+/// share is invalid.
+pub(crate) fn combine<C>(
+    label: &str,
+    base: &Element<C>,
+    shares: &HashMap<Identifier<C>, Share<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+    min_signers: u16,
+) -> Result<Element<C>, CombineError<C>>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+{
+    if shares.len() < min_signers as usize {
+        return Err(Error::IncorrectNumberOfShares.into());
+    }
+
+    let invalid: BTreeSet<_> = shares
+        .iter()
+        .filter(|(identifier, share)| {
+            !pubkeys
+                .signer_pubkeys()
+                .get(identifier)
+                .is_some_and(|verifying_share| {
+                    verify(
+                        label,
+                        &verifying_share.to_element(),
+                        base,
+                        &share.element,
+                        &share.proof,
+                    )
+                })
+        })
+        .map(|(identifier, _)| *identifier)
+        .collect();
+    if !invalid.is_empty() {
+        return Err(CombineError::InvalidShares(invalid));
+    }
+
+    let identifiers: BTreeSet<_> = shares.keys().copied().collect();
+    let mut result = BabyJubJubGroup::identity();
+    for (identifier, share) in shares {
+        let lambda = compute_lagrange_coefficient(&identifiers, None, *identifier)?;
+        result += share.element * lambda;
+    }
+    Ok(result)
+}
@@ -0,0 +1,136 @@
+//! Threshold Diffie-Hellman between a FROST group and an outside party.
+//!
+//! The group holding a [`PublicKeyPackage`] computes `secret·P` with the
+//! public key `P` of another party without reconstructing `secret`: each
+//! participant returns its [`SharedSecretShare`] `s_i·P` with a DLEQ proof
+//! against its verifying share using [`shared_secret_share`], and [`combine`]
+//! checks the proofs and interpolates at least `min_signers` shares into the
+//! [`SharedSecret`]. Invalid shares are reported with the identifiers of
+//! their senders in a [`CombineError`].
+//!
+//! [`SharedSecret::derive_key`] feeds the result to HKDF-SHA256 to get a
+//! symmetric key. Taking `P` to be the ephemeral key of an
+//! [`ecies::Ciphertext`] encrypted to the group verifying key with
+//! [`ecies::encrypt`], [`decrypt`] opens it, so that the group can receive
+//! encrypted messages.
+
+use std::collections::HashMap;
+
+use frost_core::{
+    frost::{
+        keys::{KeyPackage, PublicKeyPackage},
+        Identifier,
+    },
+    Ciphersuite, Element, Error, Group, VerifyingKey,
+};
+use hkdf::Hkdf;
+use rand_core::{CryptoRng, RngCore};
+use sha2::Sha256;
+
+use crate::dleq::{self, CombineError};
+use crate::ecies::{self, EciesError};
+use crate::{BabyJubJubGroup, BabyJubJubHash};
+
+/// The label of the shared secret share proofs.
+const LABEL: &str = "ecdh";
+
+/// The domain separation prefix of [`SharedSecret::derive_key`].
+const INFO_PREFIX: &[u8] = b"FROST-ECDH-v1";
+
+/// A participant's share `s_i·P` of the Diffie-Hellman result with `P`,
+/// with a proof against its verifying share.
+pub type SharedSecretShare<C> = dleq::Share<C>;
+
+/// The Diffie-Hellman result `secret·P` of the group with the public key
+/// `P`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct SharedSecret<C: Ciphersuite<Group = BabyJubJubGroup>> {
+    element: Element<C>,
+}
+
+impl<C: Ciphersuite<Group = BabyJubJubGroup>> SharedSecret<C> {
+    /// The element `secret·P`.
+    pub fn element(&self) -> &Element<C> {
+        &self.element
+    }
+
+    /// Derives a 32-byte key from the shared secret with HKDF-SHA256, with
+    /// `info` as the application context.
+    ///
+    /// The HKDF info is `"FROST-ECDH-v1" || ID || info`, where `ID` is the
+    /// ciphersuite ID.
+    pub fn derive_key(&self, info: &[u8]) -> [u8; 32] {
+        let mut context = INFO_PREFIX.to_vec();
+        context.extend_from_slice(C::ID.as_bytes());
+        context.extend_from_slice(info);
+
+        let mut key = [0u8; 32];
+        Hkdf::<Sha256>::new(None, &BabyJubJubGroup::serialize(&self.element))
+            .expand(&context, &mut key)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+        key
+    }
+}
+
+impl<C: Ciphersuite<Group = BabyJubJubGroup>> core::fmt::Debug for SharedSecret<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedSecret")
+            .field("element", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Computes the [`SharedSecretShare`] with `public_key` of the participant
+/// holding `key_package`.
+pub fn shared_secret_share<C, R>(
+    key_package: &KeyPackage<C>,
+    public_key: &VerifyingKey<C>,
+    rng: &mut R,
+) -> SharedSecretShare<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+    R: RngCore + CryptoRng,
+{
+    dleq::share(LABEL, key_package, &public_key.to_element(), rng)
+}
+
+/// Checks the [`SharedSecretShare`]s against `pubkeys` and combines at least
+/// `min_signers` of them into the [`SharedSecret`] with `public_key`.
+///
+/// Fails with [`CombineError::InvalidShares`] naming every participant whose
+/// share is invalid.
+pub fn combine<C>(
+    public_key: &VerifyingKey<C>,
+    shares: &HashMap<Identifier<C>, SharedSecretShare<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+    min_signers: u16,
+) -> Result<SharedSecret<C>, CombineError<C>>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+{
+    let public_key = public_key.to_element();
+    if public_key == BabyJubJubGroup::identity() {
+        return Err(Error::MalformedVerifyingKey.into());
+    }
+    let element = dleq::combine(LABEL, &public_key, shares, pubkeys, min_signers)?;
+    Ok(SharedSecret { element })
+}
+
+/// Decrypts an [`ecies::Ciphertext`] encrypted with [`ecies::encrypt`] to
+/// `group_public`, given the [`SharedSecret`] with its ephemeral key.
+pub fn decrypt<C>(
+    shared_secret: &SharedSecret<C>,
+    group_public: &VerifyingKey<C>,
+    aad: &[u8],
+    ciphertext: &ecies::Ciphertext<C>,
+) -> Result<Vec<u8>, EciesError>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+{
+    ecies::decrypt_with_shared_secret(
+        shared_secret.element,
+        group_public.to_element(),
+        aad,
+        ciphertext,
+    )
+}
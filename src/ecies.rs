@@ -0,0 +1,400 @@
+//! Hybrid encryption (ECIES) to the keys of a FROST ciphersuite.
+//!
+//! A message is encrypted to an [`EncryptionKey`], such as a
+//! [`VerifyingKey`] or a participant's [`VerifyingShare`], with an ephemeral
+//! Diffie-Hellman key exchange in the ciphersuite's group, HKDF-SHA256 and
+//! ChaCha20-Poly1305. The [`Ciphertext`] holds the ephemeral public key and
+//! the AEAD output, and is opened with the matching [`DecryptionKey`]: a
+//! [`SigningKey`], or the [`SigningShare`] or [`KeyPackage`] of the
+//! participant.
+//!
+//! [`encrypt_authenticated`] also mixes in a Diffie-Hellman exchange with
+//! the sender's static key, like the authenticated mode of HPKE, so that
+//! [`decrypt_authenticated`] only accepts ciphertexts from the holder of the
+//! expected sender key.
+//!
+//! The key schedule is
+//!
+//! ```text
+//! ikm  = ser(e·R) [|| ser(s·R)]
+//! info = "FROST-ECIES-v1" || ID || ser(E) || ser(R) [|| ser(S)]
+//! key || nonce = HKDF-SHA256(salt = "", ikm, info, 44)
+//! ```
+//!
+//! where `e`, `E` are the ephemeral key pair, `R` the recipient's key, `s`,
+//! `S` the sender's static key pair and `ID` the ciphersuite ID. The
+//! associated data given to the functions is authenticated by the AEAD.
+//!
+//! Ciphertexts are serialized as `ser(E) || AEAD output`, and
+//! [`Ciphertext::deserialize`] rejects an ephemeral key that is not a valid
+//! non-identity element of the group, e.g. off the prime-order subgroup of
+//! BabyJubJub.
+
+use chacha20poly1305::{aead::Aead, aead::Payload, ChaCha20Poly1305, KeyInit};
+use frost_core::{
+    frost::keys::{KeyPackage, SigningShare, VerifyingShare},
+    Ciphersuite, Element, Field, Group, Scalar, SigningKey, VerifyingKey,
+};
+use hkdf::Hkdf;
+use rand_core::{CryptoRng, RngCore};
+use sha2::Sha256;
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+/// The domain separation prefix of the key schedule.
+const INFO_PREFIX: &[u8] = b"FROST-ECIES-v1";
+
+/// The length of the ChaCha20-Poly1305 authentication tag.
+const TAG_LEN: usize = 16;
+
+/// An error when encrypting or decrypting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EciesError {
+    /// A key exchange resulted in the identity.
+    InvalidKey,
+    /// The serialized ciphertext is too short or its ephemeral key is not a
+    /// valid element.
+    MalformedCiphertext,
+    /// The ciphertext or its associated data was modified, or the keys do
+    /// not match.
+    DecryptionFailed,
+}
+
+impl core::fmt::Display for EciesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EciesError::InvalidKey => write!(f, "Invalid key."),
+            EciesError::MalformedCiphertext => write!(f, "Malformed ciphertext."),
+            EciesError::DecryptionFailed => write!(f, "Decryption failed."),
+        }
+    }
+}
+
+impl std::error::Error for EciesError {}
+
+/// A public key that messages can be encrypted to.
+pub trait EncryptionKey<C: Ciphersuite> {
+    /// The element of the key.
+    fn encryption_element(&self) -> Element<C>;
+}
+
+impl<C: Ciphersuite> EncryptionKey<C> for VerifyingKey<C> {
+    fn encryption_element(&self) -> Element<C> {
+        self.to_element()
+    }
+}
+
+impl<C: Ciphersuite> EncryptionKey<C> for VerifyingShare<C> {
+    fn encryption_element(&self) -> Element<C> {
+        self.to_element()
+    }
+}
+
+/// A secret key that decrypts the messages encrypted to its public key.
+pub trait DecryptionKey<C: Ciphersuite> {
+    /// The scalar of the key.
+    fn decryption_scalar(&self) -> Scalar<C>;
+}
+
+impl<C: Ciphersuite> DecryptionKey<C> for SigningKey<C> {
+    fn decryption_scalar(&self) -> Scalar<C> {
+        self.to_scalar()
+    }
+}
+
+impl<C: Ciphersuite> DecryptionKey<C> for SigningShare<C> {
+    fn decryption_scalar(&self) -> Scalar<C> {
+        self.to_scalar()
+    }
+}
+
+/// Decrypts the messages encrypted to the [`KeyPackage::public`] share.
+impl<C: Ciphersuite> DecryptionKey<C> for KeyPackage<C> {
+    fn decryption_scalar(&self) -> Scalar<C> {
+        self.secret_share().to_scalar()
+    }
+}
+
+/// A message encrypted to an [`EncryptionKey`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "C: Ciphersuite + serde::Serialize",
+        deserialize = "C: Ciphersuite + serde::Deserialize<'de>"
+    ))
+)]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Ciphertext<C: Ciphersuite> {
+    ephemeral_key: VerifyingKey<C>,
+    #[cfg_attr(feature = "serde", serde(with = "hex_bytes"))]
+    ciphertext: Vec<u8>,
+}
+
+impl<C: Ciphersuite> Ciphertext<C> {
+    /// Creates a new [`Ciphertext`].
+    pub fn new(ephemeral_key: VerifyingKey<C>, ciphertext: Vec<u8>) -> Self {
+        Self {
+            ephemeral_key,
+            ciphertext,
+        }
+    }
+
+    /// The ephemeral public key of the key exchange.
+    pub fn ephemeral_key(&self) -> &VerifyingKey<C> {
+        &self.ephemeral_key
+    }
+
+    /// The encrypted message followed by the authentication tag.
+    pub fn ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+
+    /// Serializes the ciphertext as the ephemeral key followed by the AEAD
+    /// output.
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut bytes = self.ephemeral_key.serialize().as_ref().to_vec();
+        bytes.extend_from_slice(&self.ciphertext);
+        bytes
+    }
+
+    /// Deserializes a ciphertext serialized with [`Ciphertext::serialize`],
+    /// validating its ephemeral key.
+    pub fn deserialize(bytes: &[u8]) -> Result<Self, EciesError> {
+        let element_len = <C::Group>::serialize(&<C::Group>::generator())
+            .as_ref()
+            .len();
+        if bytes.len() < element_len + TAG_LEN {
+            return Err(EciesError::MalformedCiphertext);
+        }
+        let (ephemeral_key, ciphertext) = bytes.split_at(element_len);
+        let ephemeral_key = ephemeral_key
+            .to_vec()
+            .try_into()
+            .map_err(|_| EciesError::MalformedCiphertext)?;
+        let ephemeral_key =
+            <C::Group>::deserialize(&ephemeral_key).map_err(|_| EciesError::MalformedCiphertext)?;
+        Ok(Self::new(
+            VerifyingKey::new(ephemeral_key),
+            ciphertext.to_vec(),
+        ))
+    }
+}
+
+/// Serializes bytes as a hex string, like the other frost-core types.
+#[cfg(feature = "serde")]
+mod hex_bytes {
+    use frost_core::serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&::hex::encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        ::hex::decode(string).map_err(D::Error::custom)
+    }
+}
+
+/// Returns the serialization of the Diffie-Hellman result `shared`, or an
+/// error if it is the identity.
+fn shared_secret<C: Ciphersuite>(shared: Element<C>) -> Result<Vec<u8>, EciesError> {
+    if shared == <C::Group>::identity() {
+        return Err(EciesError::InvalidKey);
+    }
+    Ok(<C::Group>::serialize(&shared).as_ref().to_vec())
+}
+
+/// Returns the serialization of `secret · element`, or an error if it is
+/// the identity.
+fn diffie_hellman<C: Ciphersuite>(
+    secret: Scalar<C>,
+    element: Element<C>,
+) -> Result<Vec<u8>, EciesError> {
+    shared_secret::<C>(element * secret)
+}
+
+/// Derives the AEAD key and nonce from the key exchanges.
+fn key_schedule<C: Ciphersuite>(
+    ikm: &[u8],
+    ephemeral_key: &Element<C>,
+    recipient: &Element<C>,
+    sender: Option<&Element<C>>,
+) -> (ChaCha20Poly1305, [u8; 12]) {
+    let mut info = INFO_PREFIX.to_vec();
+    info.extend_from_slice(C::ID.as_bytes());
+    info.extend_from_slice(<C::Group>::serialize(ephemeral_key).as_ref());
+    info.extend_from_slice(<C::Group>::serialize(recipient).as_ref());
+    if let Some(sender) = sender {
+        info.extend_from_slice(<C::Group>::serialize(sender).as_ref());
+    }
+
+    let mut okm = [0u8; 44];
+    Hkdf::<Sha256>::new(None, ikm)
+        .expand(&info, &mut okm)
+        .expect("44 bytes is a valid HKDF-SHA256 output length");
+    let cipher = ChaCha20Poly1305::new_from_slice(&okm[..32]).expect("the key is 32 bytes");
+    let nonce = okm[32..].try_into().expect("the nonce is 12 bytes");
+    (cipher, nonce)
+}
+
+/// Encrypts `plaintext` to `recipient`, optionally authenticated with the
+/// `sender` key.
+fn seal<C, R>(
+    recipient: &impl EncryptionKey<C>,
+    sender: Option<&dyn DecryptionKey<C>>,
+    aad: &[u8],
+    plaintext: &[u8],
+    rng: &mut R,
+) -> Result<Ciphertext<C>, EciesError>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    let recipient = recipient.encryption_element();
+    let ephemeral_secret = <<C::Group as Group>::Field>::random(rng);
+    let ephemeral_key = <C::Group>::generator() * ephemeral_secret;
+
+    let mut ikm = diffie_hellman::<C>(ephemeral_secret, recipient)?;
+    let sender_key = match sender {
+        Some(sender) => {
+            let sender = sender.decryption_scalar();
+            ikm.extend(diffie_hellman::<C>(sender, recipient)?);
+            Some(<C::Group>::generator() * sender)
+        }
+        None => None,
+    };
+    let (cipher, nonce) = key_schedule::<C>(&ikm, &ephemeral_key, &recipient, sender_key.as_ref());
+    let ciphertext = cipher
+        .encrypt(
+            &nonce.into(),
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
+        .expect("encryption of an in-memory message cannot fail");
+
+    Ok(Ciphertext::new(
+        VerifyingKey::new(ephemeral_key),
+        ciphertext,
+    ))
+}
+
+/// Decrypts `ciphertext` with the key material `ikm` of the key exchanges.
+fn unseal<C: Ciphersuite>(
+    ikm: &[u8],
+    recipient: &Element<C>,
+    sender: Option<&Element<C>>,
+    aad: &[u8],
+    ciphertext: &Ciphertext<C>,
+) -> Result<Vec<u8>, EciesError> {
+    let ephemeral_key = ciphertext.ephemeral_key.to_element();
+    let (cipher, nonce) = key_schedule::<C>(ikm, &ephemeral_key, recipient, sender);
+    cipher
+        .decrypt(
+            &nonce.into(),
+            Payload {
+                msg: &ciphertext.ciphertext,
+                aad,
+            },
+        )
+        .map_err(|_| EciesError::DecryptionFailed)
+}
+
+/// Decrypts `ciphertext` with `secret`, optionally checking that it was
+/// encrypted by the holder of the `sender` key.
+fn open<C: Ciphersuite>(
+    secret: &impl DecryptionKey<C>,
+    sender: Option<&dyn EncryptionKey<C>>,
+    aad: &[u8],
+    ciphertext: &Ciphertext<C>,
+) -> Result<Vec<u8>, EciesError> {
+    let secret = secret.decryption_scalar();
+    let recipient = <C::Group>::generator() * secret;
+    let ephemeral_key = ciphertext.ephemeral_key.to_element();
+
+    let mut ikm = diffie_hellman::<C>(secret, ephemeral_key)?;
+    let sender_key = match sender {
+        Some(sender) => {
+            let sender = sender.encryption_element();
+            ikm.extend(diffie_hellman::<C>(secret, sender)?);
+            Some(sender)
+        }
+        None => None,
+    };
+    unseal(&ikm, &recipient, sender_key.as_ref(), aad, ciphertext)
+}
+
+/// Decrypts a [`Ciphertext`] encrypted with [`encrypt`] to `recipient`,
+/// given the Diffie-Hellman result `shared` of its ephemeral key with the
+/// recipient's secret, e.g. computed by a threshold of participants.
+pub(crate) fn decrypt_with_shared_secret<C: Ciphersuite>(
+    shared: Element<C>,
+    recipient: Element<C>,
+    aad: &[u8],
+    ciphertext: &Ciphertext<C>,
+) -> Result<Vec<u8>, EciesError> {
+    unseal(
+        &shared_secret::<C>(shared)?,
+        &recipient,
+        None,
+        aad,
+        ciphertext,
+    )
+}
+
+/// Encrypts `plaintext` to `recipient`, authenticating `aad` with it.
+pub fn encrypt<C, R>(
+    recipient: &impl EncryptionKey<C>,
+    aad: &[u8],
+    plaintext: &[u8],
+    rng: &mut R,
+) -> Result<Ciphertext<C>, EciesError>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    seal(recipient, None, aad, plaintext, rng)
+}
+
+/// Decrypts a [`Ciphertext`] encrypted with [`encrypt`] to the public key of
+/// `secret`, with the same `aad`.
+pub fn decrypt<C: Ciphersuite>(
+    secret: &impl DecryptionKey<C>,
+    aad: &[u8],
+    ciphertext: &Ciphertext<C>,
+) -> Result<Vec<u8>, EciesError> {
+    open(secret, None, aad, ciphertext)
+}
+
+/// Encrypts `plaintext` from the holder of `sender` to `recipient`, so that
+/// the recipient can check who sent it with [`decrypt_authenticated`].
+pub fn encrypt_authenticated<C, R>(
+    sender: &impl DecryptionKey<C>,
+    recipient: &impl EncryptionKey<C>,
+    aad: &[u8],
+    plaintext: &[u8],
+    rng: &mut R,
+) -> Result<Ciphertext<C>, EciesError>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    seal(recipient, Some(sender), aad, plaintext, rng)
+}
+
+/// Decrypts a [`Ciphertext`] encrypted with [`encrypt_authenticated`] to the
+/// public key of `secret`, failing unless it was encrypted by the holder of
+/// `sender`.
+pub fn decrypt_authenticated<C: Ciphersuite>(
+    secret: &impl DecryptionKey<C>,
+    sender: &impl EncryptionKey<C>,
+    aad: &[u8],
+    ciphertext: &Ciphertext<C>,
+) -> Result<Vec<u8>, EciesError> {
+    open(secret, Some(sender), aad, ciphertext)
+}
@@ -0,0 +1,207 @@
+//! ElGamal encryption to a group verifying key, with threshold decryption by
+//! the holders of its FROST key shares.
+//!
+//! A message point `M` is encrypted to the group [`VerifyingKey`] `Y` as
+//! the [`Ciphertext`] `(C1, C2) = (r·G, M + r·Y)`. Each participant computes
+//! a [`DecryptionShare`] `s_i·C1` with a Chaum–Pedersen proof against its
+//! verifying share using [`decryption_share`], and [`combine`] checks the
+//! proofs of the shares, interpolates `secret·C1 = r·Y` from at least
+//! `min_signers` of them and returns `M = C2 - r·Y`. Invalid shares are
+//! reported with the identifiers of their senders in a [`CombineError`].
+//!
+//! The key packages from any of the key generation methods of this crate can
+//! be used, e.g. from [`dkg::part3`](crate::dkg::part3).
+//!
+//! # Exponential ElGamal
+//!
+//! [`encrypt_exponent`] encrypts an integer `m` as the point `m·G`, which
+//! makes ciphertexts additively homomorphic: the sum of ciphertexts with `+`
+//! encrypts the sum of their integers. This suits encrypted tallies, where
+//! only the final sum is decrypted by a threshold of participants, and
+//! [`discrete_log`] recovers it from `m·G` as long as it is small.
+
+use std::collections::HashMap;
+use std::ops::{Add, AddAssign};
+
+use frost_core::{
+    frost::{
+        keys::{KeyPackage, PublicKeyPackage},
+        Identifier,
+    },
+    Ciphersuite, Element, Field, Group, SigningKey, VerifyingKey,
+};
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+use crate::dleq::{self, CombineError};
+use crate::{BabyJubJubGroup, BabyJubJubHash, Fr};
+
+/// The label of the decryption share proofs.
+const LABEL: &str = "elgamal";
+
+/// A point encrypted to a [`VerifyingKey`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound = "C: Ciphersuite<Group = BabyJubJubGroup>")
+)]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Ciphertext<C: Ciphersuite<Group = BabyJubJubGroup>> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::element"))]
+    c1: Element<C>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::element"))]
+    c2: Element<C>,
+}
+
+impl<C: Ciphersuite<Group = BabyJubJubGroup>> Ciphertext<C> {
+    /// Creates a new [`Ciphertext`].
+    pub fn new(c1: Element<C>, c2: Element<C>) -> Self {
+        Self { c1, c2 }
+    }
+
+    /// The ephemeral key `C1 = r·G`.
+    pub fn c1(&self) -> &Element<C> {
+        &self.c1
+    }
+
+    /// The masked message `C2 = M + r·Y`.
+    pub fn c2(&self) -> &Element<C> {
+        &self.c2
+    }
+}
+
+impl<C: Ciphersuite<Group = BabyJubJubGroup>> Add for Ciphertext<C> {
+    type Output = Self;
+
+    /// Adds the plaintexts of both ciphertexts.
+    fn add(self, other: Self) -> Self {
+        Ciphertext::new(self.c1 + other.c1, self.c2 + other.c2)
+    }
+}
+
+impl<C: Ciphersuite<Group = BabyJubJubGroup>> AddAssign for Ciphertext<C> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+/// A participant's share `s_i·C1` of the decryption of a [`Ciphertext`],
+/// with a proof against its verifying share.
+pub type DecryptionShare<C> = dleq::Share<C>;
+
+/// Encrypts the point `message` to `verifying_key`.
+pub fn encrypt<C, R>(
+    verifying_key: &VerifyingKey<C>,
+    message: &Element<C>,
+    rng: &mut R,
+) -> Ciphertext<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+    R: RngCore + CryptoRng,
+{
+    let r = <<C::Group as Group>::Field>::random(rng);
+    Ciphertext::new(
+        BabyJubJubGroup::generator() * r,
+        *message + verifying_key.to_element() * r,
+    )
+}
+
+/// Encrypts the integer `message` as the point `message·G` to
+/// `verifying_key`.
+pub fn encrypt_exponent<C, R>(
+    verifying_key: &VerifyingKey<C>,
+    message: u64,
+    rng: &mut R,
+) -> Ciphertext<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+    R: RngCore + CryptoRng,
+{
+    let message = BabyJubJubGroup::generator() * Fr::from(message);
+    encrypt(verifying_key, &message, rng)
+}
+
+/// Decrypts `ciphertext` with the whole `signing_key`.
+pub fn decrypt<C>(signing_key: &SigningKey<C>, ciphertext: &Ciphertext<C>) -> Element<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+{
+    ciphertext.c2 - ciphertext.c1 * signing_key.to_scalar()
+}
+
+/// Computes the [`DecryptionShare`] of `ciphertext` of the participant
+/// holding `key_package`.
+pub fn decryption_share<C, R>(
+    key_package: &KeyPackage<C>,
+    ciphertext: &Ciphertext<C>,
+    rng: &mut R,
+) -> DecryptionShare<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+    R: RngCore + CryptoRng,
+{
+    dleq::share(LABEL, key_package, &ciphertext.c1, rng)
+}
+
+/// Checks the [`DecryptionShare`]s against `pubkeys` and decrypts
+/// `ciphertext` with at least `min_signers` of them.
+///
+/// Fails with [`CombineError::InvalidShares`] naming every participant whose
+/// share is invalid.
+pub fn combine<C>(
+    ciphertext: &Ciphertext<C>,
+    decryption_shares: &HashMap<Identifier<C>, DecryptionShare<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+    min_signers: u16,
+) -> Result<Element<C>, CombineError<C>>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+{
+    let mask = dleq::combine(
+        LABEL,
+        &ciphertext.c1,
+        decryption_shares,
+        pubkeys,
+        min_signers,
+    )?;
+    Ok(ciphertext.c2 - mask)
+}
+
+/// Finds the integer `m` in `0..=bound` such that `point = m·G`, with the
+/// baby-step giant-step algorithm.
+///
+/// It takes about `2·sqrt(bound)` group operations and `sqrt(bound)` memory,
+/// and returns `None` if there is no such `m`.
+pub fn discrete_log(point: &<BabyJubJubGroup as Group>::Element, bound: u64) -> Option<u64> {
+    // The smallest `step` with `step * step > bound`.
+    let mut step = (bound as f64).sqrt() as u64;
+    while step.checked_mul(step).is_some_and(|square| square <= bound) {
+        step += 1;
+    }
+    step = step.max(1);
+
+    let generator = BabyJubJubGroup::generator();
+    let mut baby_steps = HashMap::with_capacity(step as usize);
+    let mut baby_step = BabyJubJubGroup::identity();
+    for j in 0..step {
+        baby_steps
+            .entry(BabyJubJubGroup::serialize(&baby_step))
+            .or_insert(j);
+        baby_step += generator;
+    }
+
+    let giant_step = -(generator * Fr::from(step));
+    let mut gamma = *point;
+    for i in 0..step {
+        if let Some(j) = baby_steps.get(&BabyJubJubGroup::serialize(&gamma)) {
+            let m = i * step + j;
+            return (m <= bound).then_some(m);
+        }
+        gamma += giant_step;
+    }
+    None
+}
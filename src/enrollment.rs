@@ -0,0 +1,171 @@
+//! Enrollment and disenrollment of participants, keeping the group key.
+//!
+//! Enrollment uses the steps of the Repairable Threshold Scheme to give a
+//! new identifier the evaluation of the current sharing polynomial:
+//!
+//! - each helper, among at least `min_signers` current participants, calls
+//!   [`enroll_step_1`] and sends one delta to every helper;
+//! - each helper calls [`enroll_step_2`] with the deltas it received and
+//!   sends the resulting sigma to the new participant;
+//! - the new participant calls [`enroll_step_3`], which checks the share
+//!   against the verifying share interpolated from the
+//!   [`PublicKeyPackage`], and returns its [`KeyPackage`] and the updated
+//!   [`PublicKeyPackage`].
+//!
+//! The other participants update their [`PublicKeyPackage`] with
+//! [`add_participant`]. The channels between the helpers, and to the new
+//! participant, must be confidential and authenticated.
+//!
+//! Disenrollment removes participants from the [`PublicKeyPackage`] and
+//! refreshes the shares of the remaining ones (see [`crate::refresh`]), so
+//! that the removed shares no longer combine with the others.
+
+use std::collections::{BTreeSet, HashMap};
+
+use frost_core::{
+    frost::{
+        compute_lagrange_coefficient,
+        keys::{
+            repairable, KeyPackage, PublicKeyPackage, VerifiableSecretSharingCommitment,
+            VerifyingShare,
+        },
+        Identifier,
+    },
+    Ciphersuite, Error, Group, Scalar,
+};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::refresh::{compute_refreshing_shares, RefreshingShare};
+use crate::repairable::compute_deltas;
+
+/// Step 1 of enrollment, performed by each helper.
+///
+/// Generates the deltas from the helper holding `key_package` to enroll
+/// `participant`, where `helpers` contains the identifiers of all the helpers.
+/// Returns which delta to send to which helper, including itself.
+pub fn enroll_step_1<C, R>(
+    helpers: &[Identifier<C>],
+    key_package: &KeyPackage<C>,
+    rng: &mut R,
+    participant: Identifier<C>,
+) -> Result<HashMap<Identifier<C>, Scalar<C>>, Error<C>>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    compute_deltas(helpers, key_package, rng, participant)
+}
+
+/// Step 2 of enrollment, performed by each helper.
+///
+/// Sums the deltas received from all the helpers into the sigma to send to
+/// the new participant.
+pub fn enroll_step_2<C: Ciphersuite>(deltas_j: &[Scalar<C>]) -> Scalar<C> {
+    repairable::repair_share_step_2::<C>(deltas_j)
+}
+
+/// Step 3 of enrollment, performed by the new participant.
+///
+/// Sums the sigmas received from the helpers into the signing share of
+/// `identifier`, checks it against `pubkeys`, and returns the new
+/// participant's [`KeyPackage`] with the threshold `min_signers` and the
+/// updated [`PublicKeyPackage`].
+pub fn enroll_step_3<C: Ciphersuite>(
+    sigmas: &[Scalar<C>],
+    identifier: Identifier<C>,
+    min_signers: u16,
+    pubkeys: &PublicKeyPackage<C>,
+) -> Result<(KeyPackage<C>, PublicKeyPackage<C>), Error<C>> {
+    let pubkeys = add_participant(pubkeys, identifier)?;
+    let verifying_share = pubkeys.signer_pubkeys()[&identifier];
+
+    let commitment = VerifiableSecretSharingCommitment::deserialize(Vec::new())?;
+    let signing_share = *repairable::repair_share_step_3(sigmas, identifier, &commitment).value();
+    if VerifyingShare::from(signing_share) != verifying_share {
+        return Err(Error::InvalidSecretShare);
+    }
+
+    let key_package = KeyPackage::new(
+        identifier,
+        signing_share,
+        verifying_share,
+        *pubkeys.group_public(),
+        min_signers,
+    );
+    Ok((key_package, pubkeys))
+}
+
+/// Adds the verifying share of the enrolled `identifier` to `pubkeys`,
+/// interpolating it from the verifying shares of the current participants.
+pub fn add_participant<C: Ciphersuite>(
+    pubkeys: &PublicKeyPackage<C>,
+    identifier: Identifier<C>,
+) -> Result<PublicKeyPackage<C>, Error<C>> {
+    let mut signer_pubkeys = pubkeys.signer_pubkeys().clone();
+    if signer_pubkeys.contains_key(&identifier) {
+        return Err(Error::DuplicatedIdentifier);
+    }
+
+    let identifiers: BTreeSet<_> = signer_pubkeys.keys().copied().collect();
+    let mut element = <C::Group>::identity();
+    for (id, verifying_share) in &signer_pubkeys {
+        let lambda = compute_lagrange_coefficient(&identifiers, Some(identifier), *id)?;
+        element = element + verifying_share.to_element() * lambda;
+    }
+    signer_pubkeys.insert(identifier, VerifyingShare::new(element));
+
+    Ok(PublicKeyPackage::new(
+        signer_pubkeys,
+        *pubkeys.group_public(),
+    ))
+}
+
+/// Removes the verifying shares of `identifiers` from `pubkeys`.
+///
+/// The removed participants can still sign with the remaining ones until
+/// the shares are refreshed, e.g. with the distributed
+/// [`refresh::dkg`](crate::refresh::dkg) among the remaining participants
+/// using the returned [`PublicKeyPackage`]. [`disenroll`] does both with a
+/// trusted dealer.
+pub fn remove_participants<C: Ciphersuite>(
+    pubkeys: &PublicKeyPackage<C>,
+    identifiers: &[Identifier<C>],
+) -> Result<PublicKeyPackage<C>, Error<C>> {
+    let mut signer_pubkeys = pubkeys.signer_pubkeys().clone();
+    for identifier in identifiers {
+        signer_pubkeys
+            .remove(identifier)
+            .ok_or(Error::UnknownIdentifier)?;
+    }
+    Ok(PublicKeyPackage::new(
+        signer_pubkeys,
+        *pubkeys.group_public(),
+    ))
+}
+
+/// Disenrolls `identifiers` as a trusted dealer: removes them from `pubkeys`
+/// and generates the [`RefreshingShare`]s of the remaining participants,
+/// who apply them with [`refresh_share`](crate::refresh::refresh_share).
+///
+/// `min_signers` must be the threshold the keys were generated with, and at
+/// least that many participants must remain.
+#[allow(clippy::type_complexity)]
+pub fn disenroll<C, R>(
+    pubkeys: &PublicKeyPackage<C>,
+    identifiers: &[Identifier<C>],
+    min_signers: u16,
+    rng: &mut R,
+) -> Result<
+    (
+        HashMap<Identifier<C>, RefreshingShare<C>>,
+        PublicKeyPackage<C>,
+    ),
+    Error<C>,
+>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    let pubkeys = remove_participants(pubkeys, identifiers)?;
+    compute_refreshing_shares(&pubkeys, min_signers, rng)
+}
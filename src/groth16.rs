@@ -0,0 +1,200 @@
+//! Groth16 proofs that a message was signed under a verifying key.
+//!
+//! A [`Proof`] shows that the prover knows a valid signature of a message
+//! under a FROST [`VerifyingKey`] without revealing the signature. It is
+//! checked with the [`r1cs`](crate::r1cs) gadget over BN254, so it is
+//! available for the ciphersuites implementing [`CircuitCiphersuite`] with a
+//! field element message, i.e. the Poseidon ciphersuites. The circuit of
+//! the SHA-256 ciphersuite depends on the message length, which a
+//! circuit-specific setup cannot.
+//!
+//! The public inputs are the affine coordinates of the verifying key and the
+//! message field element returned by [`CircuitCiphersuite::message`].
+//! [`verify`] computes them from the verifying key and message bytes.
+//!
+//! Groth16 needs a circuit-specific trusted [`setup`]: whoever knows its
+//! randomness can forge proofs, so the keys must come from the verifier or a
+//! setup ceremony. Proofs, proving keys and verifying keys are serialized with
+//! the arkworks compressed encoding.
+
+use core::marker::PhantomData;
+
+use ark_bn254::Bn254;
+use ark_ed_on_bn254::constraints::FqVar;
+use ark_groth16::Groth16;
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use frost_core::{Signature, VerifyingKey};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::poseidon::decode_point;
+use crate::r1cs::{CircuitCiphersuite, SignatureVar, VerifyingKeyVar};
+use crate::Fq;
+
+/// An error when creating or verifying a proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// The signature to prove is not valid.
+    InvalidSignature,
+    /// The proof is not valid.
+    InvalidProof,
+    /// A proof or key could not be deserialized.
+    MalformedEncoding,
+    /// The circuit could not be synthesized.
+    Synthesis(SynthesisError),
+}
+
+impl core::fmt::Display for ProofError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ProofError::InvalidSignature => write!(f, "Invalid signature."),
+            ProofError::InvalidProof => write!(f, "Invalid proof."),
+            ProofError::MalformedEncoding => write!(f, "Malformed proof or key encoding."),
+            ProofError::Synthesis(e) => write!(f, "Circuit synthesis failed: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ProofError {}
+
+impl From<SynthesisError> for ProofError {
+    fn from(e: SynthesisError) -> Self {
+        ProofError::Synthesis(e)
+    }
+}
+
+/// The circuit proving that `signature` is valid for the message field
+/// element `m` under `verifying_key`. All values are `None` during setup.
+struct SignatureCircuit<'a, C: CircuitCiphersuite> {
+    verifying_key: Option<&'a VerifyingKey<C>>,
+    m: Option<Fq>,
+    signature: Option<&'a Signature<C>>,
+}
+
+impl<C> ConstraintSynthesizer<Fq> for SignatureCircuit<'_, C>
+where
+    C: CircuitCiphersuite<Message = Fq, MessageVar = FqVar, SignatureSerialization = [u8; 64]>,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fq>) -> Result<(), SynthesisError> {
+        let verifying_key = VerifyingKeyVar::new_input(cs.clone(), || {
+            self.verifying_key.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let m = FqVar::new_input(cs.clone(), || {
+            self.m.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let signature = SignatureVar::new_witness(cs, || {
+            self.signature.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        verifying_key.enforce_verify(&m, &signature)
+    }
+}
+
+/// Implements the arkworks compressed serialization for a key or proof
+/// wrapper.
+macro_rules! serialization {
+    ($name:ident) => {
+        impl<C> $name<C> {
+            /// Serializes with the arkworks compressed encoding.
+            pub fn serialize(&self) -> Vec<u8> {
+                let mut bytes = Vec::new();
+                self.0
+                    .serialize_with_mode(&mut bytes, Compress::Yes)
+                    .expect("serialization into a Vec should succeed");
+                bytes
+            }
+
+            /// Deserializes from the arkworks compressed encoding.
+            pub fn deserialize(bytes: &[u8]) -> Result<Self, ProofError> {
+                CanonicalDeserialize::deserialize_with_mode(bytes, Compress::Yes, Validate::Yes)
+                    .map(|inner| $name(inner, PhantomData))
+                    .map_err(|_| ProofError::MalformedEncoding)
+            }
+        }
+    };
+}
+
+/// The key to create proofs for the ciphersuite `C`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProvingKey<C>(ark_groth16::ProvingKey<Bn254>, PhantomData<C>);
+
+/// The key to verify proofs for the ciphersuite `C`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProofVerifyingKey<C>(ark_groth16::VerifyingKey<Bn254>, PhantomData<C>);
+
+/// A proof of a valid signature for the ciphersuite `C`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Proof<C>(ark_groth16::Proof<Bn254>, PhantomData<C>);
+
+serialization!(ProvingKey);
+serialization!(ProofVerifyingKey);
+serialization!(Proof);
+
+/// Generates the keys to create and verify proofs.
+pub fn setup<C, R>(rng: &mut R) -> Result<(ProvingKey<C>, ProofVerifyingKey<C>), ProofError>
+where
+    C: CircuitCiphersuite<Message = Fq, MessageVar = FqVar, SignatureSerialization = [u8; 64]>,
+    R: RngCore + CryptoRng,
+{
+    let circuit = SignatureCircuit::<C> {
+        verifying_key: None,
+        m: None,
+        signature: None,
+    };
+    let proving_key = Groth16::<Bn254>::generate_random_parameters_with_reduction(circuit, rng)?;
+    let verifying_key = proving_key.vk.clone();
+    Ok((
+        ProvingKey(proving_key, PhantomData),
+        ProofVerifyingKey(verifying_key, PhantomData),
+    ))
+}
+
+/// Proves that `signature` is valid for `msg` under `verifying_key`.
+///
+/// The signature is checked first, since proving a false statement would
+/// silently produce an invalid proof.
+pub fn prove<C, R>(
+    proving_key: &ProvingKey<C>,
+    verifying_key: &VerifyingKey<C>,
+    msg: &[u8],
+    signature: &Signature<C>,
+    rng: &mut R,
+) -> Result<Proof<C>, ProofError>
+where
+    C: CircuitCiphersuite<Message = Fq, MessageVar = FqVar, SignatureSerialization = [u8; 64]>,
+    R: RngCore + CryptoRng,
+{
+    verifying_key
+        .verify(msg, signature)
+        .map_err(|_| ProofError::InvalidSignature)?;
+
+    let circuit = SignatureCircuit {
+        verifying_key: Some(verifying_key),
+        m: Some(C::message(msg)),
+        signature: Some(signature),
+    };
+    let proof = Groth16::<Bn254>::create_random_proof_with_reduction(circuit, &proving_key.0, rng)?;
+    Ok(Proof(proof, PhantomData))
+}
+
+/// Verifies that `proof` shows a valid signature of `msg` under
+/// `verifying_key`.
+pub fn verify<C>(
+    proof_verifying_key: &ProofVerifyingKey<C>,
+    verifying_key: &VerifyingKey<C>,
+    msg: &[u8],
+    proof: &Proof<C>,
+) -> Result<(), ProofError>
+where
+    C: CircuitCiphersuite<Message = Fq>,
+{
+    let A = decode_point(&verifying_key.serialize()).expect("group elements are valid points");
+    let inputs = [A.x, A.y, C::message(msg)];
+
+    let prepared = ark_groth16::prepare_verifying_key(&proof_verifying_key.0);
+    if Groth16::<Bn254>::verify_proof(&prepared, &proof.0, &inputs)? {
+        Ok(())
+    } else {
+        Err(ProofError::InvalidProof)
+    }
+}
@@ -0,0 +1,49 @@
+//! The FROST(Jubjub, SHA-256) ciphersuite.
+//!
+//! Jubjub is the twisted Edwards curve defined over the scalar field of
+//! BLS12-381, which makes it cheap to use inside BLS12-381 based proof
+//! systems. The group is its prime-order subgroup with the arkworks
+//! generator, and points use the arkworks compressed encoding.
+//!
+//! This ciphersuite hashes with SHA-256 and is *not* compatible with the
+//! RedJubjub signatures used by Zcash.
+
+use ark_ed_on_bls12_381::{Fr, JubjubConfig};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+use crate::{
+    babyjubjub_ciphersuite, frost_api, sha256_hash_to_array, sha256_hash_to_scalar, BabyJubJubHash,
+    TEGroup, TEScalarField,
+};
+
+/// An implementation of the FROST(Jubjub, SHA-256) ciphersuite scalar field.
+pub type JubjubScalarField = TEScalarField<JubjubConfig>;
+
+/// An implementation of the FROST(Jubjub, SHA-256) ciphersuite group.
+pub type JubjubGroup = TEGroup<JubjubConfig>;
+
+/// An implementation of the FROST(Jubjub, SHA-256) ciphersuite.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+pub struct JubjubSha256;
+
+impl BabyJubJubHash<Fr> for JubjubSha256 {
+    const ID: &'static str = "FROST(Jubjub, SHA-256)";
+
+    const CONTEXT_STRING: &'static str = "FROST-Jubjub-SHA256-v1";
+
+    fn hash_to_scalar(domain: &[u8], msg: &[u8]) -> Fr {
+        sha256_hash_to_scalar(domain, msg)
+    }
+
+    fn hash_to_array(domain: &[u8], msg: &[u8]) -> [u8; 32] {
+        sha256_hash_to_array(domain, msg)
+    }
+}
+
+babyjubjub_ciphersuite!(JubjubSha256, group = JubjubGroup);
+
+frost_api!(JubjubSha256, "FROST(Jubjub, SHA-256)");
@@ -0,0 +1,78 @@
+//! The FROST(babyjubjub, Keccak-256) ciphersuite.
+//!
+//! All hashes use Keccak-256, the hash natively available to the EVM, so
+//! that signatures can be verified cheaply by Solidity contracts.
+
+use ark_ed_on_bn254::Fr;
+use ark_ff::PrimeField;
+use sha3::{Digest, Keccak256};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+use crate::{babyjubjub_ciphersuite, frost_api, BabyJubJubHash};
+
+/// Context string for the FROST(babyjubjub, Keccak-256) ciphersuite.
+const CONTEXT_STRING: &str = "FROST-babyjubjub-KECCAK256-v1";
+
+pub(crate) fn hash_to_array(inputs: &[&[u8]]) -> [u8; 32] {
+    let mut h = Keccak256::new();
+    for i in inputs {
+        h.update(i);
+    }
+    let mut output = [0u8; 32];
+    output.copy_from_slice(h.finalize().as_slice());
+    output
+}
+
+/// Hashes `msg` with domain separation `domain` to a scalar as described in
+/// [`BabyJubJubKeccak256`].
+pub(crate) fn hash_to_scalar(domain: &[u8], msg: &[u8]) -> Fr {
+    let mut wide = [0u8; 64];
+    wide[..32].copy_from_slice(&hash_to_array(&[domain, msg, &[0]]));
+    wide[32..].copy_from_slice(&hash_to_array(&[domain, msg, &[1]]));
+    Fr::from_be_bytes_mod_order(&wide)
+}
+
+/// An implementation of the FROST(babyjubjub, Keccak-256) ciphersuite.
+///
+/// H4 and H5 are `keccak256(contextString || "msg" || m)` and
+/// `keccak256(contextString || "com" || m)`, with the context string
+/// `FROST-babyjubjub-KECCAK256-v1`.
+///
+/// H1, H2, H3, HDKG and HID hash to a scalar with the domain separation tag
+/// `dst = contextString || label`, where the label is `rho`, `chal`, `nonce`,
+/// `dkg` or `id` respectively. Two digests are computed,
+///
+/// ```text
+/// h0 = keccak256(dst || m || 0x00)
+/// h1 = keccak256(dst || m || 0x01)
+/// ```
+///
+/// and the 512-bit big-endian integer `h0 || h1` is reduced modulo the group
+/// order `r`. In Solidity this is
+/// `addmod(mulmod(uint256(h0), R256, r), uint256(h1), r)` with the constant
+/// `R256 = 2^256 mod r`. The wide reduction keeps the bias of the result below
+/// `2^-260`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+pub struct BabyJubJubKeccak256;
+
+impl BabyJubJubHash for BabyJubJubKeccak256 {
+    const ID: &'static str = "FROST(babyjubjub, Keccak-256)";
+
+    const CONTEXT_STRING: &'static str = CONTEXT_STRING;
+
+    fn hash_to_scalar(domain: &[u8], msg: &[u8]) -> Fr {
+        hash_to_scalar(domain, msg)
+    }
+
+    fn hash_to_array(domain: &[u8], msg: &[u8]) -> [u8; 32] {
+        hash_to_array(&[domain, msg])
+    }
+}
+
+babyjubjub_ciphersuite!(BabyJubJubKeccak256);
+
+frost_api!(BabyJubJubKeccak256, "FROST(babyjubjub, Keccak-256)");
@@ -0,0 +1,1129 @@
+//! Macros to define BabyJubJub ciphersuites and their FROST APIs.
+
+use crate::BabyJubJubHash;
+
+/// Implements [`Ciphersuite`](crate::Ciphersuite) for a type implementing
+/// [`BabyJubJubHash`](crate::BabyJubJubHash).
+///
+/// The resulting ciphersuite uses [`BabyJubJubGroup`](crate::BabyJubJubGroup),
+/// or the group given with the optional `group = ...` argument, e.g. a
+/// [`TEGroup`](crate::TEGroup) over another curve, in which case the type
+/// must implement `BabyJubJubHash` for the scalar field of that group. Every
+/// hash is derived from the context string and a label:
+///
+/// - H1, H3, HDKG and HID call `hash_to_scalar` with the domain
+///   `contextString || label`, where the label is `rho`, `nonce`, `dkg` and
+///   `id` respectively;
+/// - H2 calls `challenge`, which by default uses the label `chal`;
+/// - H4 and H5 call `hash_to_array` with the labels `msg` and `com`.
+///
+/// # Example
+///
+/// ```
+/// use frost_bjj::{BabyJubJubHash, BabyJubJubSha256, Fr};
+///
+/// /// FROST(babyjubjub, SHA-256) with an application-specific context string.
+/// #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// pub struct MyCiphersuite;
+///
+/// impl BabyJubJubHash for MyCiphersuite {
+///     const ID: &'static str = "FROST(babyjubjub, SHA-256, my-app)";
+///     const CONTEXT_STRING: &'static str = "my-app-FROST-babyjubjub-SHA256-v1";
+///
+///     fn hash_to_scalar(domain: &[u8], msg: &[u8]) -> Fr {
+///         BabyJubJubSha256::hash_to_scalar(domain, msg)
+///     }
+///
+///     fn hash_to_array(domain: &[u8], msg: &[u8]) -> [u8; 32] {
+///         BabyJubJubSha256::hash_to_array(domain, msg)
+///     }
+/// }
+///
+/// frost_bjj::babyjubjub_ciphersuite!(MyCiphersuite);
+///
+/// /// FROST(babyjubjub, SHA-256, my-app) keys, signing and aggregation.
+/// pub mod my_frost {
+///     frost_bjj::frost_api!(super::MyCiphersuite, "FROST(babyjubjub, SHA-256, my-app)");
+/// }
+///
+/// # fn main() -> Result<(), my_frost::Error> {
+/// let mut rng = rand::thread_rng();
+/// let (_shares, _pubkeys) = my_frost::keys::generate_with_dealer(
+///     3,
+///     2,
+///     my_frost::keys::IdentifierList::Default,
+///     &mut rng,
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! babyjubjub_ciphersuite {
+    ($suite:ty $(,)?) => {
+        $crate::babyjubjub_ciphersuite!($suite, group = $crate::BabyJubJubGroup);
+    };
+    ($suite:ty, group = $group:ty $(,)?) => {
+        impl $crate::Ciphersuite for $suite {
+            const ID: &'static str =
+                <$suite as $crate::BabyJubJubHash<$crate::frost_core::Scalar<Self>>>::ID;
+
+            type Group = $group;
+
+            type HashOutput = [u8; 32];
+
+            type SignatureSerialization = [u8; 64];
+
+            fn H1(m: &[u8]) -> $crate::frost_core::Scalar<Self> {
+                $crate::macros::hash_to_scalar::<$suite, _>("rho", m)
+            }
+
+            fn H2(m: &[u8]) -> $crate::frost_core::Scalar<Self> {
+                <$suite as $crate::BabyJubJubHash<$crate::frost_core::Scalar<Self>>>::challenge(m)
+            }
+
+            fn H3(m: &[u8]) -> $crate::frost_core::Scalar<Self> {
+                $crate::macros::hash_to_scalar::<$suite, _>("nonce", m)
+            }
+
+            fn H4(m: &[u8]) -> Self::HashOutput {
+                $crate::macros::hash_to_array::<$suite, $crate::frost_core::Scalar<Self>>("msg", m)
+            }
+
+            fn H5(m: &[u8]) -> Self::HashOutput {
+                $crate::macros::hash_to_array::<$suite, $crate::frost_core::Scalar<Self>>("com", m)
+            }
+
+            fn HDKG(m: &[u8]) -> Option<$crate::frost_core::Scalar<Self>> {
+                Some($crate::macros::hash_to_scalar::<$suite, _>("dkg", m))
+            }
+
+            fn HID(m: &[u8]) -> Option<$crate::frost_core::Scalar<Self>> {
+                Some($crate::macros::hash_to_scalar::<$suite, _>("id", m))
+            }
+        }
+    };
+}
+
+/// [`BabyJubJubHash::hash_to_scalar`] with the domain
+/// `CONTEXT_STRING || label`, used by [`babyjubjub_ciphersuite!`].
+pub fn hash_to_scalar<H: BabyJubJubHash<S>, S>(label: &str, m: &[u8]) -> S {
+    H::hash_to_scalar((H::CONTEXT_STRING.to_owned() + label).as_bytes(), m)
+}
+
+/// [`BabyJubJubHash::hash_to_array`] with the domain
+/// `CONTEXT_STRING || label`, used by [`babyjubjub_ciphersuite!`].
+pub fn hash_to_array<H: BabyJubJubHash<S>, S>(label: &str, m: &[u8]) -> [u8; 32] {
+    H::hash_to_array((H::CONTEXT_STRING.to_owned() + label).as_bytes(), m)
+}
+
+/// Defines the FROST API of a ciphersuite in the calling module.
+///
+/// This generates the same items as the root of this crate does for
+/// FROST(babyjubjub, SHA-256): `Error`, `Identifier`, `keys` (with `dkg`,
+/// `repairable`, `refresh`, `resharing` and `enrollment`), `round1`,
+/// `SigningPackage`, `round2`, `Signature`, `aggregate`,
+/// `aggregate_identifying_cheaters`, `rerandomized`, `SigningKey` and
+/// `VerifyingKey`, all specialized to the given ciphersuite. The name is only
+/// used in the documentation of those items.
+///
+/// Invoke it in a dedicated module, since the generated items use these
+/// common names. An optional `dkg_doc = ...` argument replaces the
+/// documentation of the generated `keys::dkg` module.
+///
+/// See [`babyjubjub_ciphersuite!`] for an example.
+#[macro_export]
+macro_rules! frost_api {
+    ($suite:ty, $name:literal $(,)?) => {
+        $crate::frost_api!(
+            $suite,
+            $name,
+            dkg_doc = concat!(
+                "Distributed key generation for ",
+                $name,
+                ".\n\n",
+                "Each participant calls `part1`, `part2` and `part3` in turn. ",
+                "The `round1::Package` is broadcast to every other participant, ",
+                "while each `round2::Package` is sent to its recipient over a ",
+                "confidential and authenticated channel.",
+            ),
+        );
+    };
+    ($suite:ty, $name:literal, dkg_doc = $dkg_doc:expr $(,)?) => {
+        type __Ciphersuite = $suite;
+
+        #[doc = concat!("A ", $name, " error.")]
+        pub type Error = $crate::frost_core::Error<__Ciphersuite>;
+
+        #[doc = concat!("A ", $name, " participant identifier.")]
+        pub type Identifier = $crate::frost_core::frost::Identifier<__Ciphersuite>;
+
+        #[doc = concat!($name, " keys, key generation, key shares.")]
+        pub mod keys {
+            use super::{Error, Identifier, SigningKey, __Ciphersuite};
+            use $crate::frost_core::frost;
+            use $crate::rand_core::{CryptoRng, RngCore};
+            use ::std::collections::HashMap;
+
+            /// The identifier list to use when generating key shares.
+            pub type IdentifierList<'a> = frost::keys::IdentifierList<'a, __Ciphersuite>;
+
+            /// Allows all participants' keys to be generated using a central, trusted
+            /// dealer.
+            pub fn generate_with_dealer<RNG: RngCore + CryptoRng>(
+                max_signers: u16,
+                min_signers: u16,
+                identifiers: IdentifierList,
+                mut rng: RNG,
+            ) -> Result<(HashMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+                frost::keys::generate_with_dealer(max_signers, min_signers, identifiers, &mut rng)
+            }
+
+            /// Splits an existing key into FROST shares.
+            ///
+            /// This is identical to [`generate_with_dealer`] but receives an existing key
+            /// instead of generating a fresh one. This is useful in scenarios where
+            /// the key needs to be generated externally or must be derived from e.g. a
+            /// seed phrase.
+            pub fn split<R: RngCore + CryptoRng>(
+                secret: &SigningKey,
+                max_signers: u16,
+                min_signers: u16,
+                identifiers: IdentifierList,
+                rng: &mut R,
+            ) -> Result<(HashMap<Identifier, SecretShare>, PublicKeyPackage), Error> {
+                frost::keys::split(secret, max_signers, min_signers, identifiers, rng)
+            }
+
+            /// Recompute the secret from t-of-n secret shares using Lagrange interpolation.
+            ///
+            /// This can be used if for some reason the original key must be restored; e.g.
+            /// if threshold signing is not required anymore.
+            ///
+            /// This is NOT required to sign with FROST; the whole point of FROST is being
+            /// able to generate signatures only using the shares, without having to
+            /// reconstruct the original key.
+            ///
+            /// The caller is responsible for providing at least `min_signers` shares;
+            /// if less than that is provided, a different key will be returned.
+            pub fn reconstruct(secret_shares: &[KeyPackage]) -> Result<SigningKey, Error> {
+                frost::keys::reconstruct(secret_shares)
+            }
+
+            /// Secret and public key material generated by a dealer performing
+            /// [`generate_with_dealer`].
+            ///
+            /// # Security
+            ///
+            #[doc = concat!("To derive a ", $name, " keypair, the receiver of the [`SecretShare`] *must* call")]
+            /// .into(), which under the hood also performs validation.
+            pub type SecretShare = frost::keys::SecretShare<__Ciphersuite>;
+
+            /// A secret scalar value representing a signer's share of the group secret.
+            pub type SigningShare = frost::keys::SigningShare<__Ciphersuite>;
+
+            /// A public group element that represents a single signer's public verification share.
+            pub type VerifyingShare = frost::keys::VerifyingShare<__Ciphersuite>;
+
+            #[doc = concat!("A ", $name, " keypair, which can be generated either by a trusted dealer or using")]
+            /// a DKG.
+            ///
+            /// When using a central dealer, [`SecretShare`]s are distributed to
+            /// participants, who then perform verification, before deriving
+            /// [`KeyPackage`]s, which they store to later use during signing.
+            pub type KeyPackage = frost::keys::KeyPackage<__Ciphersuite>;
+
+            /// Public data that contains all the signers' public keys as well as the
+            /// group public key.
+            ///
+            /// Used for verification purposes before publishing a signature.
+            pub type PublicKeyPackage = frost::keys::PublicKeyPackage<__Ciphersuite>;
+
+            /// Contains the commitments to the coefficients for our secret polynomial _f_,
+            /// used to generate participants' key shares.
+            ///
+            /// [`VerifiableSecretSharingCommitment`] contains a set of commitments to the coefficients (which
+            /// themselves are scalars) for a secret polynomial f, where f is used to
+            /// generate each ith participant's key share f(i). Participants use this set of
+            /// commitments to perform verifiable secret sharing.
+            ///
+            /// Note that participants MUST be assured that they have the *same*
+            /// [`VerifiableSecretSharingCommitment`], either by performing pairwise comparison, or by using
+            /// some agreed-upon public location for publication, where each participant can
+            /// ensure that they received the correct (and same) value.
+            pub type VerifiableSecretSharingCommitment =
+                frost::keys::VerifiableSecretSharingCommitment<__Ciphersuite>;
+
+            pub mod dkg {
+                #![doc = $dkg_doc]
+
+                use super::super::{Error, Identifier, __Ciphersuite};
+                use super::{KeyPackage, PublicKeyPackage};
+                use $crate::frost_core::frost;
+                use $crate::rand_core::{CryptoRng, RngCore};
+                use ::std::collections::HashMap;
+
+                /// DKG Round 1 structures.
+                pub mod round1 {
+                    use super::{frost, __Ciphersuite};
+
+                    /// The secret package that must be kept in memory by the participant
+                    /// between the first and second parts of the DKG protocol (round 1).
+                    ///
+                    /// # Security
+                    ///
+                    /// This package MUST NOT be sent to other participants!
+                    pub type SecretPackage = frost::keys::dkg::round1::SecretPackage<__Ciphersuite>;
+
+                    /// The package that must be broadcast by each participant to all other participants
+                    /// between the first and second parts of the DKG protocol (round 1).
+                    pub type Package = frost::keys::dkg::round1::Package<__Ciphersuite>;
+                }
+
+                /// DKG Round 2 structures.
+                pub mod round2 {
+                    use super::{frost, __Ciphersuite};
+
+                    /// The secret package that must be kept in memory by the participant
+                    /// between the second and third parts of the DKG protocol (round 2).
+                    ///
+                    /// # Security
+                    ///
+                    /// This package MUST NOT be sent to other participants!
+                    pub type SecretPackage = frost::keys::dkg::round2::SecretPackage<__Ciphersuite>;
+
+                    /// A package that must be sent by each participant to some other participants
+                    /// in Round 2 of the DKG protocol. Note that there is one specific package
+                    /// for each specific recipient, in contrast to Round 1.
+                    ///
+                    /// # Security
+                    ///
+                    /// The package must be sent on an *confidential* and *authenticated* channel.
+                    pub type Package = frost::keys::dkg::round2::Package<__Ciphersuite>;
+                }
+
+                /// Performs the first part of the distributed key generation protocol
+                /// for the given participant.
+                ///
+                /// It returns the [`round1::SecretPackage`] that must be kept in memory
+                /// by the participant for the other steps, and the [`round1::Package`] that
+                /// must be sent to other participants.
+                pub fn part1<R: RngCore + CryptoRng>(
+                    identifier: Identifier,
+                    max_signers: u16,
+                    min_signers: u16,
+                    mut rng: R,
+                ) -> Result<(round1::SecretPackage, round1::Package), Error> {
+                    frost::keys::dkg::part1(identifier, max_signers, min_signers, &mut rng)
+                }
+
+                /// Performs the second part of the distributed key generation protocol
+                /// for the participant holding the given [`round1::SecretPackage`],
+                /// given the received [`round1::Package`]s received from the other participants.
+                ///
+                /// It returns the [`round2::SecretPackage`] that must be kept in memory
+                /// by the participant for the final step, and the [`round2::Package`]s that
+                /// must be sent to other participants.
+                pub fn part2(
+                    secret_package: round1::SecretPackage,
+                    round1_packages: &HashMap<Identifier, round1::Package>,
+                ) -> Result<(round2::SecretPackage, HashMap<Identifier, round2::Package>), Error> {
+                    frost::keys::dkg::part2(secret_package, round1_packages)
+                }
+
+                /// Performs the third and final part of the distributed key generation protocol
+                /// for the participant holding the given [`round2::SecretPackage`],
+                /// given the received [`round1::Package`]s and [`round2::Package`]s received from
+                /// the other participants.
+                ///
+                /// It returns the [`KeyPackage`] that has the long-lived key share for the
+                /// participant, and the [`PublicKeyPackage`]s that has public information
+                /// about all participants; both of which are required to compute FROST
+                /// signatures.
+                pub fn part3(
+                    round2_secret_package: &round2::SecretPackage,
+                    round1_packages: &HashMap<Identifier, round1::Package>,
+                    round2_packages: &HashMap<Identifier, round2::Package>,
+                ) -> Result<(KeyPackage, PublicKeyPackage), Error> {
+                    frost::keys::dkg::part3(round2_secret_package, round1_packages, round2_packages)
+                }
+
+                /// DKG with a complaint phase, in which a participant that sent an
+                /// invalid share is disqualified and the DKG completes among the others.
+                ///
+                /// The round 1 and round 2 packages are the same as above. See
+                /// `frost_bjj::dkg` for the protocol.
+                pub mod complaints {
+                    use super::super::super::{Error, Identifier, __Ciphersuite};
+                    use super::super::{KeyPackage, PublicKeyPackage};
+                    use super::{round1::Package as Round1Package, round2::Package as Round2Package};
+                    use $crate::rand_core::{CryptoRng, RngCore};
+                    use ::std::collections::{BTreeSet, HashMap};
+
+                    /// The secret package that must be kept in memory by the participant
+                    /// between [`part1`] and [`part2`].
+                    ///
+                    /// # Security
+                    ///
+                    /// This package MUST NOT be sent to other participants!
+                    pub type Round1SecretPackage = $crate::dkg::round1::SecretPackage<__Ciphersuite>;
+
+                    /// The secret package that must be kept in memory by the participant
+                    /// between [`part2`] and [`part3`].
+                    ///
+                    /// # Security
+                    ///
+                    /// This package MUST NOT be sent to other participants!
+                    pub type Round2SecretPackage = $crate::dkg::round2::SecretPackage<__Ciphersuite>;
+
+                    /// A complaint against a participant that sent an invalid or no share.
+                    pub type Complaint = $crate::dkg::Complaint<__Ciphersuite>;
+
+                    /// The answer to a [`Complaint`], revealing the share sent to the accuser.
+                    pub type Justification = $crate::dkg::Justification<__Ciphersuite>;
+
+                    /// Performs the first part of the DKG for the given participant.
+                    pub fn part1<R: RngCore + CryptoRng>(
+                        identifier: Identifier,
+                        max_signers: u16,
+                        min_signers: u16,
+                        rng: R,
+                    ) -> Result<(Round1SecretPackage, Round1Package), Error> {
+                        $crate::dkg::part1(identifier, max_signers, min_signers, rng)
+                    }
+
+                    /// Performs the second part of the DKG, disqualifying the participants
+                    /// with an invalid proof of knowledge.
+                    pub fn part2(
+                        secret_package: Round1SecretPackage,
+                        round1_packages: &HashMap<Identifier, Round1Package>,
+                    ) -> Result<(Round2SecretPackage, HashMap<Identifier, Round2Package>), Error> {
+                        $crate::dkg::part2(secret_package, round1_packages)
+                    }
+
+                    /// Returns a [`Complaint`] against every participant whose share is
+                    /// missing or invalid.
+                    pub fn complain(
+                        round2_secret_package: &Round2SecretPackage,
+                        round1_packages: &HashMap<Identifier, Round1Package>,
+                        round2_packages: &HashMap<Identifier, Round2Package>,
+                    ) -> Vec<Complaint> {
+                        $crate::dkg::complain(round2_secret_package, round1_packages, round2_packages)
+                    }
+
+                    /// Answers the complaints against the participant with a
+                    /// [`Justification`] each.
+                    pub fn justify(
+                        round2_secret_package: &Round2SecretPackage,
+                        complaints: &[Complaint],
+                    ) -> Vec<Justification> {
+                        $crate::dkg::justify(round2_secret_package, complaints)
+                    }
+
+                    /// Returns the disqualified participants, given the round 1 packages
+                    /// of all the participants and all the complaints and justifications.
+                    pub fn disqualified(
+                        round1_packages: &HashMap<Identifier, Round1Package>,
+                        min_signers: u16,
+                        complaints: &[Complaint],
+                        justifications: &[Justification],
+                    ) -> Result<BTreeSet<Identifier>, Error> {
+                        $crate::dkg::disqualified(round1_packages, min_signers, complaints, justifications)
+                    }
+
+                    /// Performs the third and final part of the DKG, returning the
+                    /// [`KeyPackage`] of the participant, the [`PublicKeyPackage`] of the
+                    /// qualified participants, and the disqualified participants.
+                    #[allow(clippy::type_complexity)]
+                    pub fn part3(
+                        round2_secret_package: &Round2SecretPackage,
+                        round1_packages: &HashMap<Identifier, Round1Package>,
+                        round2_packages: &HashMap<Identifier, Round2Package>,
+                        complaints: &[Complaint],
+                        justifications: &[Justification],
+                    ) -> Result<(KeyPackage, PublicKeyPackage, BTreeSet<Identifier>), Error> {
+                        $crate::dkg::part3(
+                            round2_secret_package,
+                            round1_packages,
+                            round2_packages,
+                            complaints,
+                            justifications,
+                        )
+                    }
+
+                    /// The DKG with complaints over a public broadcast board, with round 2
+                    /// packages encrypted to their recipients.
+                    ///
+                    /// See `frost_bjj::dkg::encrypted` for the protocol.
+                    pub mod encrypted {
+                        use super::super::super::super::{Error, Identifier, __Ciphersuite};
+                        use super::super::super::{KeyPackage, PublicKeyPackage};
+                        use super::{Complaint, Justification};
+                        use $crate::rand_core::{CryptoRng, RngCore};
+                        use ::std::collections::{BTreeSet, HashMap};
+
+                        /// The package that must be broadcast by each participant in round 1,
+                        /// with its encryption key.
+                        pub type Round1Package = $crate::dkg::encrypted::round1::Package<__Ciphersuite>;
+
+                        /// The secret package that must be kept in memory by the participant
+                        /// between [`part1`] and [`part2`].
+                        ///
+                        /// # Security
+                        ///
+                        /// This package MUST NOT be sent to other participants!
+                        pub type Round1SecretPackage =
+                            $crate::dkg::encrypted::round1::SecretPackage<__Ciphersuite>;
+
+                        /// An encrypted package from one participant to another in round 2,
+                        /// which can be broadcast.
+                        pub type Round2Package = $crate::dkg::encrypted::round2::Package<__Ciphersuite>;
+
+                        /// The secret package that must be kept in memory by the participant
+                        /// between [`part2`] and [`part3`].
+                        ///
+                        /// # Security
+                        ///
+                        /// This package MUST NOT be sent to other participants!
+                        pub type Round2SecretPackage =
+                            $crate::dkg::encrypted::round2::SecretPackage<__Ciphersuite>;
+
+                        /// Performs the first part of the DKG for the given participant and
+                        /// generates its encryption key.
+                        pub fn part1<R: RngCore + CryptoRng>(
+                            identifier: Identifier,
+                            max_signers: u16,
+                            min_signers: u16,
+                            rng: R,
+                        ) -> Result<(Round1SecretPackage, Round1Package), Error> {
+                            $crate::dkg::encrypted::part1(identifier, max_signers, min_signers, rng)
+                        }
+
+                        /// Performs the second part of the DKG and encrypts each round 2
+                        /// package to its recipient.
+                        pub fn part2<R: RngCore + CryptoRng>(
+                            secret_package: Round1SecretPackage,
+                            round1_packages: &HashMap<Identifier, Round1Package>,
+                            rng: R,
+                        ) -> Result<(Round2SecretPackage, HashMap<Identifier, Round2Package>), Error> {
+                            $crate::dkg::encrypted::part2(secret_package, round1_packages, rng)
+                        }
+
+                        /// Returns a [`Complaint`] against every participant whose share is
+                        /// missing, cannot be decrypted or is invalid.
+                        pub fn complain(
+                            round2_secret_package: &Round2SecretPackage,
+                            round1_packages: &HashMap<Identifier, Round1Package>,
+                            round2_packages: &HashMap<Identifier, Round2Package>,
+                        ) -> Vec<Complaint> {
+                            $crate::dkg::encrypted::complain(
+                                round2_secret_package,
+                                round1_packages,
+                                round2_packages,
+                            )
+                        }
+
+                        /// Answers the complaints against the participant with a
+                        /// [`Justification`] each.
+                        pub fn justify(
+                            round2_secret_package: &Round2SecretPackage,
+                            complaints: &[Complaint],
+                        ) -> Vec<Justification> {
+                            $crate::dkg::encrypted::justify(round2_secret_package, complaints)
+                        }
+
+                        /// Returns the disqualified participants, given the round 1 packages
+                        /// of all the participants and all the complaints and justifications.
+                        pub fn disqualified(
+                            round1_packages: &HashMap<Identifier, Round1Package>,
+                            min_signers: u16,
+                            complaints: &[Complaint],
+                            justifications: &[Justification],
+                        ) -> Result<BTreeSet<Identifier>, Error> {
+                            $crate::dkg::encrypted::disqualified(
+                                round1_packages,
+                                min_signers,
+                                complaints,
+                                justifications,
+                            )
+                        }
+
+                        /// Performs the third and final part of the DKG after decrypting the
+                        /// round 2 packages.
+                        #[allow(clippy::type_complexity)]
+                        pub fn part3(
+                            round2_secret_package: &Round2SecretPackage,
+                            round1_packages: &HashMap<Identifier, Round1Package>,
+                            round2_packages: &HashMap<Identifier, Round2Package>,
+                            complaints: &[Complaint],
+                            justifications: &[Justification],
+                        ) -> Result<(KeyPackage, PublicKeyPackage, BTreeSet<Identifier>), Error> {
+                            $crate::dkg::encrypted::part3(
+                                round2_secret_package,
+                                round1_packages,
+                                round2_packages,
+                                complaints,
+                                justifications,
+                            )
+                        }
+                    }
+                }
+            }
+
+            /// Repairable Threshold Scheme
+            ///
+            /// Implements the Repairable Threshold Scheme (RTS) from <https://eprint.iacr.org/2017/1155>.
+            /// The RTS is used to help a signer (participant) repair their lost share. This is achieved
+            /// using a subset of the other signers know here as `helpers`.
+            pub mod repairable {
+                use super::super::{Error, Identifier, __Ciphersuite};
+                use super::{SecretShare, VerifiableSecretSharingCommitment};
+                use $crate::frost_core::{frost, Scalar};
+                use $crate::rand_core::{CryptoRng, RngCore};
+                use ::std::collections::HashMap;
+
+                /// Step 1 of RTS.
+                ///
+                /// Generates the "delta" values from `helper_i` to help `participant` recover their share
+                /// where `helpers` contains the identifiers of all the helpers (including `helper_i`), and `share_i`
+                /// is the share of `helper_i`.
+                ///
+                /// Returns a HashMap mapping which value should be sent to which participant.
+                pub fn repair_share_step_1<R: RngCore + CryptoRng>(
+                    helpers: &[Identifier],
+                    share_i: &SecretShare,
+                    rng: &mut R,
+                    participant: Identifier,
+                ) -> Result<HashMap<Identifier, Scalar<__Ciphersuite>>, Error> {
+                    frost::keys::repairable::repair_share_step_1(helpers, share_i, rng, participant)
+                }
+
+                /// Step 2 of RTS.
+                ///
+                /// Generates the `sigma` values from all `deltas` received from `helpers`
+                /// to help `participant` recover their share.
+                /// `sigma` is the sum of all received `delta` and the `delta_i` generated for `helper_i`.
+                pub fn repair_share_step_2(
+                    deltas_j: &[Scalar<__Ciphersuite>],
+                ) -> Scalar<__Ciphersuite> {
+                    frost::keys::repairable::repair_share_step_2::<__Ciphersuite>(deltas_j)
+                }
+
+                /// Step 3 of RTS
+                ///
+                /// The `participant` sums all `sigma_j` received to compute the `share`. The `SecretShare`
+                /// is made up of the `identifier`and `commitment` of the `participant` as well as the
+                /// `value` which is the `SigningShare`.
+                pub fn repair_share_step_3(
+                    sigmas: &[Scalar<__Ciphersuite>],
+                    identifier: Identifier,
+                    commitment: &VerifiableSecretSharingCommitment,
+                ) -> SecretShare {
+                    frost::keys::repairable::repair_share_step_3(sigmas, identifier, commitment)
+                }
+
+                /// RTS with typed, serializable messages that are checked against
+                /// commitments, so that a faulty helper can be identified.
+                ///
+                /// See `frost_bjj::repairable` for the protocol.
+                pub mod verifiable {
+                    use super::super::super::{Identifier, __Ciphersuite};
+                    use super::super::{
+                        KeyPackage, PublicKeyPackage, SecretShare, VerifiableSecretSharingCommitment,
+                    };
+                    use super::Error;
+                    use $crate::rand_core::{CryptoRng, RngCore};
+                    use ::std::collections::HashMap;
+
+                    /// An error when repairing a share, which may name the faulty helper.
+                    pub type RepairError = $crate::repairable::RepairError<__Ciphersuite>;
+
+                    /// A delta sent by a helper to another helper in step 1.
+                    pub type Delta = $crate::repairable::Delta<__Ciphersuite>;
+
+                    /// A sigma sent by a helper to the participant in step 2.
+                    pub type Sigma = $crate::repairable::Sigma<__Ciphersuite>;
+
+                    /// Step 1 of RTS, performed by each helper.
+                    ///
+                    /// Returns which [`Delta`] to send to which helper, including itself.
+                    pub fn repair_share_step_1<R: RngCore + CryptoRng>(
+                        helpers: &[Identifier],
+                        key_package: &KeyPackage,
+                        rng: &mut R,
+                        participant: Identifier,
+                    ) -> Result<HashMap<Identifier, Delta>, Error> {
+                        $crate::repairable::repair_share_step_1(helpers, key_package, rng, participant)
+                    }
+
+                    /// Step 2 of RTS, performed by each helper.
+                    ///
+                    /// Checks the received [`Delta`]s and sums them into the [`Sigma`] to
+                    /// send to the participant.
+                    pub fn repair_share_step_2(
+                        identifier: Identifier,
+                        deltas: &[Delta],
+                    ) -> Result<Sigma, RepairError> {
+                        $crate::repairable::repair_share_step_2(identifier, deltas)
+                    }
+
+                    /// Step 3 of RTS, performed by the participant.
+                    ///
+                    /// Checks the received [`Sigma`]s and returns the repaired
+                    /// [`SecretShare`], checked against `commitment` and `pubkeys`.
+                    pub fn repair_share_step_3(
+                        sigmas: &[Sigma],
+                        identifier: Identifier,
+                        commitment: &VerifiableSecretSharingCommitment,
+                        pubkeys: &PublicKeyPackage,
+                    ) -> Result<SecretShare, RepairError> {
+                        $crate::repairable::repair_share_step_3(sigmas, identifier, commitment, pubkeys)
+                    }
+                }
+            }
+            /// Proactive refresh of key shares, which re-randomizes every
+            /// [`SigningShare`] and
+            /// [`VerifyingShare`] while keeping the group
+            /// verifying key.
+            ///
+            /// See `frost_bjj::refresh` for the protocol.
+            pub mod refresh {
+                use super::super::{Error, Identifier, __Ciphersuite};
+                use super::{KeyPackage, PublicKeyPackage};
+                use $crate::rand_core::{CryptoRng, RngCore};
+                use ::std::collections::HashMap;
+
+                /// A share of zero that refreshes the key share of a participant.
+                ///
+                /// # Security
+                ///
+                /// The refreshing share must be sent over a confidential and
+                /// authenticated channel.
+                pub type RefreshingShare = $crate::refresh::RefreshingShare<__Ciphersuite>;
+
+                /// Generates the refreshing shares of all the participants of
+                /// `pubkeys` as a trusted dealer, and the refreshed
+                /// [`PublicKeyPackage`].
+                ///
+                /// `min_signers` must be the threshold the keys were generated with.
+                pub fn compute_refreshing_shares<R: RngCore + CryptoRng>(
+                    pubkeys: &PublicKeyPackage,
+                    min_signers: u16,
+                    rng: &mut R,
+                ) -> Result<(HashMap<Identifier, RefreshingShare>, PublicKeyPackage), Error> {
+                    $crate::refresh::compute_refreshing_shares(pubkeys, min_signers, rng)
+                }
+
+                /// Refreshes `key_package` with the [`RefreshingShare`] received
+                /// from the dealer, after checking it.
+                pub fn refresh_share(
+                    refreshing_share: RefreshingShare,
+                    key_package: &KeyPackage,
+                ) -> Result<KeyPackage, Error> {
+                    $crate::refresh::refresh_share(refreshing_share, key_package)
+                }
+
+                /// Distributed refresh, without a trusted dealer.
+                ///
+                /// Every participant calls `part1`, `part2` and `part3` in turn. The
+                /// `round1::Package` is broadcast to every other participant, while
+                /// each `round2::Package` is sent to its recipient over a
+                /// confidential and authenticated channel.
+                pub mod dkg {
+                    use super::super::super::{Error, Identifier, __Ciphersuite};
+                    use super::super::{KeyPackage, PublicKeyPackage};
+                    use $crate::rand_core::{CryptoRng, RngCore};
+                    use ::std::collections::HashMap;
+
+                    /// Refresh round 1 structures.
+                    pub mod round1 {
+                        use super::__Ciphersuite;
+
+                        /// The secret package that must be kept in memory by the
+                        /// participant between the first and second parts of the refresh.
+                        ///
+                        /// # Security
+                        ///
+                        /// This package MUST NOT be sent to other participants!
+                        pub type SecretPackage =
+                            $crate::refresh::dkg::round1::SecretPackage<__Ciphersuite>;
+
+                        /// The package that must be broadcast by each participant to
+                        /// all other participants in round 1.
+                        pub type Package = $crate::refresh::dkg::round1::Package<__Ciphersuite>;
+                    }
+
+                    /// Refresh round 2 structures.
+                    pub mod round2 {
+                        use super::__Ciphersuite;
+
+                        /// The secret package that must be kept in memory by the
+                        /// participant between the second and third parts of the refresh.
+                        ///
+                        /// # Security
+                        ///
+                        /// This package MUST NOT be sent to other participants!
+                        pub type SecretPackage =
+                            $crate::refresh::dkg::round2::SecretPackage<__Ciphersuite>;
+
+                        /// A package that must be sent by each participant to one other
+                        /// participant in round 2, over a confidential and
+                        /// authenticated channel.
+                        pub type Package = $crate::refresh::dkg::round2::Package<__Ciphersuite>;
+                    }
+
+                    /// Performs the first part of the distributed refresh for the
+                    /// given participant.
+                    pub fn part1<R: RngCore + CryptoRng>(
+                        identifier: Identifier,
+                        max_signers: u16,
+                        min_signers: u16,
+                        rng: R,
+                    ) -> Result<(round1::SecretPackage, round1::Package), Error> {
+                        $crate::refresh::dkg::part1(identifier, max_signers, min_signers, rng)
+                    }
+
+                    /// Performs the second part of the distributed refresh, given the
+                    /// [`round1::Package`]s received from the other participants.
+                    pub fn part2(
+                        secret_package: round1::SecretPackage,
+                        round1_packages: &HashMap<Identifier, round1::Package>,
+                    ) -> Result<(round2::SecretPackage, HashMap<Identifier, round2::Package>), Error> {
+                        $crate::refresh::dkg::part2(secret_package, round1_packages)
+                    }
+
+                    /// Performs the third and final part of the distributed refresh,
+                    /// returning the refreshed [`KeyPackage`] and [`PublicKeyPackage`].
+                    pub fn part3(
+                        round2_secret_package: &round2::SecretPackage,
+                        round1_packages: &HashMap<Identifier, round1::Package>,
+                        round2_packages: &HashMap<Identifier, round2::Package>,
+                        key_package: &KeyPackage,
+                        pubkeys: &PublicKeyPackage,
+                    ) -> Result<(KeyPackage, PublicKeyPackage), Error> {
+                        $crate::refresh::dkg::part3(
+                            round2_secret_package,
+                            round1_packages,
+                            round2_packages,
+                            key_package,
+                            pubkeys,
+                        )
+                    }
+                }
+            }
+
+            /// Resharing of the group secret to a new set of participants and
+            /// threshold, keeping the group verifying key.
+            ///
+            /// Each dealer among the current participants calls `part1` and sends
+            /// each resulting [`SecretShare`] to its new participant over a
+            /// confidential and authenticated channel; each new participant then
+            /// calls `part2`. See `frost_bjj::resharing` for the protocol.
+            pub mod resharing {
+                use super::super::{Error, Identifier};
+                use super::{KeyPackage, PublicKeyPackage, SecretShare};
+                use $crate::rand_core::{CryptoRng, RngCore};
+                use ::std::collections::{BTreeSet, HashMap};
+
+                /// Performed by each dealer: shares its weighted signing share among
+                /// `new_identifiers` with the threshold `new_min_signers`.
+                ///
+                /// `dealers` are the identifiers of the current participants taking
+                /// part, at least the current `min_signers` of them.
+                pub fn part1<R: RngCore + CryptoRng>(
+                    key_package: &KeyPackage,
+                    dealers: &BTreeSet<Identifier>,
+                    new_identifiers: &[Identifier],
+                    new_min_signers: u16,
+                    rng: &mut R,
+                ) -> Result<HashMap<Identifier, SecretShare>, Error> {
+                    $crate::resharing::part1(key_package, dealers, new_identifiers, new_min_signers, rng)
+                }
+
+                /// Performed by each new participant: checks the [`SecretShare`]s
+                /// received from all the dealers against the current `pubkeys` and
+                /// combines them into the participant's [`KeyPackage`] and the new
+                /// [`PublicKeyPackage`].
+                pub fn part2(
+                    identifier: Identifier,
+                    new_identifiers: &[Identifier],
+                    new_min_signers: u16,
+                    secret_shares: &HashMap<Identifier, SecretShare>,
+                    pubkeys: &PublicKeyPackage,
+                ) -> Result<(KeyPackage, PublicKeyPackage), Error> {
+                    $crate::resharing::part2(
+                        identifier,
+                        new_identifiers,
+                        new_min_signers,
+                        secret_shares,
+                        pubkeys,
+                    )
+                }
+            }
+
+            /// Enrollment of new participants and disenrollment of existing ones,
+            /// keeping the group verifying key.
+            ///
+            /// Enrollment follows the steps of the [`repairable`] scheme: the helpers
+            /// call `enroll_step_1` and `enroll_step_2`, and the new participant
+            /// calls `enroll_step_3`. See `frost_bjj::enrollment` for the protocol.
+            pub mod enrollment {
+                use super::super::{Error, Identifier, __Ciphersuite};
+                use super::{refresh::RefreshingShare, KeyPackage, PublicKeyPackage};
+                use $crate::frost_core::Scalar;
+                use $crate::rand_core::{CryptoRng, RngCore};
+                use ::std::collections::HashMap;
+
+                /// Step 1 of enrollment, performed by each helper.
+                ///
+                /// Returns which delta to send to which helper to enroll
+                /// `participant`, where `helpers` contains the identifiers of all
+                /// the helpers.
+                pub fn enroll_step_1<R: RngCore + CryptoRng>(
+                    helpers: &[Identifier],
+                    key_package: &KeyPackage,
+                    rng: &mut R,
+                    participant: Identifier,
+                ) -> Result<HashMap<Identifier, Scalar<__Ciphersuite>>, Error> {
+                    $crate::enrollment::enroll_step_1(helpers, key_package, rng, participant)
+                }
+
+                /// Step 2 of enrollment, performed by each helper.
+                ///
+                /// Sums the received deltas into the sigma to send to the new
+                /// participant.
+                pub fn enroll_step_2(deltas_j: &[Scalar<__Ciphersuite>]) -> Scalar<__Ciphersuite> {
+                    $crate::enrollment::enroll_step_2::<__Ciphersuite>(deltas_j)
+                }
+
+                /// Step 3 of enrollment, performed by the new participant.
+                ///
+                /// Returns its [`KeyPackage`] and the updated [`PublicKeyPackage`].
+                pub fn enroll_step_3(
+                    sigmas: &[Scalar<__Ciphersuite>],
+                    identifier: Identifier,
+                    min_signers: u16,
+                    pubkeys: &PublicKeyPackage,
+                ) -> Result<(KeyPackage, PublicKeyPackage), Error> {
+                    $crate::enrollment::enroll_step_3(sigmas, identifier, min_signers, pubkeys)
+                }
+
+                /// Adds the verifying share of the enrolled `identifier` to `pubkeys`.
+                pub fn add_participant(
+                    pubkeys: &PublicKeyPackage,
+                    identifier: Identifier,
+                ) -> Result<PublicKeyPackage, Error> {
+                    $crate::enrollment::add_participant(pubkeys, identifier)
+                }
+
+                /// Removes the verifying shares of `identifiers` from `pubkeys`. The
+                /// remaining participants must then refresh their shares.
+                pub fn remove_participants(
+                    pubkeys: &PublicKeyPackage,
+                    identifiers: &[Identifier],
+                ) -> Result<PublicKeyPackage, Error> {
+                    $crate::enrollment::remove_participants(pubkeys, identifiers)
+                }
+
+                /// Disenrolls `identifiers` as a trusted dealer, returning the
+                /// [`RefreshingShare`]s of the remaining participants and their
+                /// [`PublicKeyPackage`].
+                pub fn disenroll<R: RngCore + CryptoRng>(
+                    pubkeys: &PublicKeyPackage,
+                    identifiers: &[Identifier],
+                    min_signers: u16,
+                    rng: &mut R,
+                ) -> Result<(HashMap<Identifier, RefreshingShare>, PublicKeyPackage), Error> {
+                    $crate::enrollment::disenroll(pubkeys, identifiers, min_signers, rng)
+                }
+            }
+        }
+
+        #[doc = concat!($name, " Round 1 functionality and types.")]
+        pub mod round1 {
+            use super::keys::SigningShare;
+            use super::__Ciphersuite;
+            use $crate::frost_core::frost;
+            use $crate::rand_core::{CryptoRng, RngCore};
+
+            #[doc = concat!("Comprised of ", $name, " hiding and binding nonces.")]
+            ///
+            /// Note that [`SigningNonces`] must be used *only once* for a signing
+            /// operation; re-using nonces will result in leakage of a signer's long-lived
+            /// signing key.
+            pub type SigningNonces = frost::round1::SigningNonces<__Ciphersuite>;
+
+            /// Published by each participant in the first round of the signing protocol.
+            ///
+            /// This step can be batched if desired by the implementation. Each
+            /// SigningCommitment can be used for exactly *one* signature.
+            pub type SigningCommitments = frost::round1::SigningCommitments<__Ciphersuite>;
+
+            /// A commitment to a signing nonce share.
+            pub type NonceCommitment = frost::round1::NonceCommitment<__Ciphersuite>;
+
+            /// Performed once by each participant selected for the signing operation.
+            ///
+            /// Generates the signing nonces and commitments to be used in the signing
+            /// operation.
+            pub fn commit<RNG>(
+                secret: &SigningShare,
+                rng: &mut RNG,
+            ) -> (SigningNonces, SigningCommitments)
+            where
+                RNG: CryptoRng + RngCore,
+            {
+                frost::round1::commit::<__Ciphersuite, RNG>(secret, rng)
+            }
+        }
+
+        /// Generated by the coordinator of the signing operation and distributed to
+        /// each signing party.
+        pub type SigningPackage = $crate::frost_core::frost::SigningPackage<__Ciphersuite>;
+
+        #[doc = concat!($name, " Round 2 functionality and types, for signature share generation.")]
+        pub mod round2 {
+            use super::{keys, round1, Error, SigningPackage, __Ciphersuite};
+            use $crate::frost_core::frost;
+
+            #[doc = concat!("A ", $name, " participant's signature share, which the Coordinator will aggregate with all other signer's")]
+            /// shares into the joint signature.
+            pub type SignatureShare = frost::round2::SignatureShare<__Ciphersuite>;
+
+            /// Performed once by each participant selected for the signing operation.
+            ///
+            /// Receives the message to be signed and a set of signing commitments and a set
+            /// of randomizing commitments to be used in that signing operation, including
+            /// that for this participant.
+            ///
+            /// Assumes the participant has already determined which nonce corresponds with
+            /// the commitment that was assigned by the coordinator in the SigningPackage.
+            pub fn sign(
+                signing_package: &SigningPackage,
+                signer_nonces: &round1::SigningNonces,
+                key_package: &keys::KeyPackage,
+            ) -> Result<SignatureShare, Error> {
+                frost::round2::sign(signing_package, signer_nonces, key_package)
+            }
+        }
+
+        #[doc = concat!("A Schnorr signature on ", $name, ".")]
+        pub type Signature = $crate::frost_core::Signature<__Ciphersuite>;
+
+        #[doc = concat!("Verifies each ", $name, " participant's signature share, and if all are valid,")]
+        /// aggregates the shares into a signature to publish.
+        ///
+        /// Resulting signature is compatible with verification of a plain Schnorr
+        /// signature.
+        ///
+        /// This operation is performed by a coordinator that can communicate with all
+        /// the signing participants before publishing the final signature. The
+        /// coordinator can be one of the participants or a semi-trusted third party
+        /// (who is trusted to not perform denial of service attacks, but does not learn
+        /// any secret information). Note that because the coordinator is trusted to
+        /// report misbehaving parties in order to avoid publishing an invalid
+        /// signature, if the coordinator themselves is a signer and misbehaves, they
+        /// can avoid that step. However, at worst, this results in a denial of
+        /// service attack due to publishing an invalid signature.
+        pub fn aggregate(
+            signing_package: &SigningPackage,
+            signature_shares: &::std::collections::HashMap<Identifier, round2::SignatureShare>,
+            pubkeys: &keys::PublicKeyPackage,
+        ) -> Result<Signature, Error> {
+            $crate::frost_core::frost::aggregate(signing_package, signature_shares, pubkeys)
+        }
+
+        /// Why a signature share was rejected by [`aggregate_identifying_cheaters`].
+        pub use $crate::cheaters::ShareFault;
+
+        /// An error when aggregating signature shares, listing all the signers that
+        /// misbehaved.
+        pub type AggregateError = $crate::cheaters::AggregateError<__Ciphersuite>;
+
+        /// Finds the signers whose signature share is missing, unexpected or invalid.
+        ///
+        /// Returns an empty map if every signer of the signing package sent a valid
+        /// share.
+        pub fn identify_cheaters(
+            signing_package: &SigningPackage,
+            signature_shares: &::std::collections::HashMap<Identifier, round2::SignatureShare>,
+            pubkeys: &keys::PublicKeyPackage,
+        ) -> Result<::std::collections::BTreeMap<Identifier, ShareFault>, Error> {
+            $crate::cheaters::identify_cheaters(signing_package, signature_shares, pubkeys)
+        }
+
+        /// Aggregates the signature shares like [`aggregate`], but on failure
+        /// returns all the faulty signers with the reason for each of them, so that
+        /// the coordinator can exclude them all in one retry.
+        pub fn aggregate_identifying_cheaters(
+            signing_package: &SigningPackage,
+            signature_shares: &::std::collections::HashMap<Identifier, round2::SignatureShare>,
+            pubkeys: &keys::PublicKeyPackage,
+        ) -> Result<Signature, AggregateError> {
+            $crate::cheaters::aggregate(signing_package, signature_shares, pubkeys)
+        }
+
+        #[doc = concat!("Re-randomized ", $name, " signing, for signatures that cannot be linked to the group")]
+        /// verifying key.
+        ///
+        /// Round 1 is the same as regular FROST. The Coordinator then generates
+        /// [`RandomizedParams`](rerandomized::RandomizedParams) and sends its
+        /// randomizer to every participant over a confidential channel, along with
+        /// the [`SigningPackage`]. Participants call [`rerandomized::sign`] and the
+        /// Coordinator calls [`rerandomized::aggregate`]. The resulting signature
+        /// verifies under the
+        /// [`randomized_verifying_key`](rerandomized::RandomizedParams::randomized_verifying_key),
+        /// which is the group verifying key plus the randomizer times the generator.
+        pub mod rerandomized {
+            use super::{keys, round1, round2, Error, Identifier, Signature, SigningPackage, __Ciphersuite};
+            use $crate::frost_rerandomized;
+
+            /// A randomizer: a random scalar which is used to randomize the keys.
+            ///
+            /// It must be generated uniformly at random, and must be kept
+            /// confidential to keep the signature unlinkable.
+            pub type Randomizer = frost_rerandomized::Randomizer<__Ciphersuite>;
+
+            /// The parameters of a re-randomized signing operation: the
+            /// randomizer, its element and the randomized verifying key.
+            pub type RandomizedParams = frost_rerandomized::RandomizedParams<__Ciphersuite>;
+
+            /// Performed once by each participant selected for the signing operation,
+            /// with the `randomizer` received from the Coordinator.
+            ///
+            /// See [`round2::sign`] for the other parameters.
+            pub fn sign(
+                signing_package: &SigningPackage,
+                signer_nonces: &round1::SigningNonces,
+                key_package: &keys::KeyPackage,
+                randomizer: Randomizer,
+            ) -> Result<round2::SignatureShare, Error> {
+                frost_rerandomized::sign(signing_package, signer_nonces, key_package, randomizer)
+            }
+
+            /// Verifies each participant's signature share against the randomized
+            /// verifying shares, and if all are valid, aggregates the shares into a
+            /// signature valid under the randomized verifying key.
+            ///
+            /// See [`aggregate`](super::aggregate) for the other parameters.
+            pub fn aggregate(
+                signing_package: &SigningPackage,
+                signature_shares: &::std::collections::HashMap<Identifier, round2::SignatureShare>,
+                pubkeys: &keys::PublicKeyPackage,
+                randomized_params: &RandomizedParams,
+            ) -> Result<Signature, Error> {
+                frost_rerandomized::aggregate(
+                    signing_package,
+                    signature_shares,
+                    pubkeys,
+                    randomized_params,
+                )
+            }
+        }
+
+        #[doc = concat!("A signing key for a Schnorr signature on ", $name, ".")]
+        pub type SigningKey = $crate::frost_core::SigningKey<__Ciphersuite>;
+
+        #[doc = concat!("A valid verifying key for Schnorr signatures on ", $name, ".")]
+        pub type VerifyingKey = $crate::frost_core::VerifyingKey<__Ciphersuite>;
+    };
+}
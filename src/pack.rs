@@ -0,0 +1,206 @@
+//! The circomlibjs encoding of points and signatures.
+//!
+//! circomlibjs' `babyJub.packPoint` encodes a point as its `y` coordinate in
+//! 32 little-endian bytes, with the most significant bit of the last byte set
+//! when `x` is "negative", i.e. greater than `(p - 1) / 2`. `eddsa.packSignature`
+//! appends the 32 little-endian bytes of `S` to the packed `R8`.
+//!
+//! The [`Pack`] trait applies this encoding to [`VerifyingKey`],
+//! [`VerifyingShare`], [`NonceCommitment`] and [`Signature`] of every
+//! BabyJubJub ciphersuite in this crate. With the circomlib curve parameters
+//! used here the packed bytes coincide with the default serialization of
+//! those types, so both can be used interchangeably; this module spells the
+//! encoding out for interoperability and, with the `serde` feature, provides
+//! the `hex` adapter to serialize those types as the hex strings produced by
+//! circomlibjs' `Buffer.toString("hex")`.
+//!
+//! Unpacking is stricter than circomlibjs: non-canonical `y` coordinates and
+//! `S` values, the sign bit set on a point with `x = 0`, and points outside of
+//! the prime-order subgroup are rejected.
+
+use ark_ec::twisted_edwards::TECurveConfig;
+use ark_ff::{BigInteger, Field as ArkField, One, PrimeField, Zero};
+use frost_core::{
+    frost::{keys::VerifyingShare, round1::NonceCommitment},
+    Ciphersuite, Error, Signature, VerifyingKey,
+};
+
+use crate::babyjubjub::{EdwardsAffine, EdwardsConfig};
+use crate::poseidon::decode_point;
+use crate::{BabyJubJubGroup, Fq, Fr, Group, GroupError};
+
+/// Packs `point` like circomlibjs' `babyJub.packPoint`.
+pub fn pack_point(point: &EdwardsAffine) -> [u8; 32] {
+    let mut packed = [0u8; 32];
+    packed.copy_from_slice(&point.y.into_bigint().to_bytes_le());
+    if point.x.into_bigint() > Fq::MODULUS_MINUS_ONE_DIV_TWO {
+        packed[31] |= 0x80;
+    }
+    packed
+}
+
+/// Unpacks a point encoded with [`pack_point`].
+///
+/// Returns `None` if the bytes do not encode a point on the curve. The point
+/// is not checked to be in the prime-order subgroup.
+pub fn unpack_point(packed: &[u8; 32]) -> Option<EdwardsAffine> {
+    let mut bytes = *packed;
+    let sign = bytes[31] & 0x80 != 0;
+    bytes[31] &= 0x7f;
+
+    let y = Fq::from_le_bytes_mod_order(&bytes);
+    if y.into_bigint().to_bytes_le() != bytes {
+        return None;
+    }
+
+    // x^2 = (1 - y^2) / (a - d * y^2)
+    let y2 = y.square();
+    let denominator = EdwardsConfig::COEFF_A - EdwardsConfig::COEFF_D * y2;
+    let x2 = (Fq::one() - y2) * denominator.inverse()?;
+    let mut x = x2.sqrt()?;
+
+    if x.is_zero() && sign {
+        return None;
+    }
+    if (x.into_bigint() > Fq::MODULUS_MINUS_ONE_DIV_TWO) != sign {
+        x = -x;
+    }
+
+    Some(EdwardsAffine::new_unchecked(x, y))
+}
+
+/// Types with a circomlibjs-compatible packed encoding.
+pub trait Pack: Sized {
+    /// The packed encoding.
+    type Packed: AsRef<[u8]> + for<'a> TryFrom<&'a [u8]>;
+
+    /// The error returned when unpacking fails.
+    type Error: core::fmt::Display;
+
+    /// Encodes `self` as circomlibjs does.
+    fn pack(&self) -> Self::Packed;
+
+    /// Decodes a value encoded with [`Pack::pack`].
+    fn unpack(packed: &Self::Packed) -> Result<Self, Self::Error>;
+}
+
+/// Repacks the default serialization of a group element.
+fn pack_element(bytes: &[u8; 32]) -> [u8; 32] {
+    pack_point(&decode_point(bytes).expect("group elements are valid points"))
+}
+
+/// Unpacks a group element into its default serialization, which still has
+/// to be deserialized to check that it is in the prime-order subgroup.
+fn unpack_element(packed: &[u8; 32]) -> Result<[u8; 32], GroupError> {
+    let point = unpack_point(packed).ok_or(GroupError::MalformedElement)?;
+    Ok(BabyJubJubGroup::serialize(&point.into()))
+}
+
+impl<C> Pack for VerifyingKey<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+{
+    type Packed = [u8; 32];
+    type Error = Error<C>;
+
+    fn pack(&self) -> [u8; 32] {
+        pack_element(&self.serialize())
+    }
+
+    fn unpack(packed: &[u8; 32]) -> Result<Self, Error<C>> {
+        let bytes = unpack_element(packed).map_err(|_| Error::MalformedVerifyingKey)?;
+        Self::deserialize(bytes)
+    }
+}
+
+impl<C> Pack for VerifyingShare<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+{
+    type Packed = [u8; 32];
+    type Error = Error<C>;
+
+    fn pack(&self) -> [u8; 32] {
+        pack_element(&self.serialize())
+    }
+
+    fn unpack(packed: &[u8; 32]) -> Result<Self, Error<C>> {
+        Self::deserialize(unpack_element(packed)?)
+    }
+}
+
+impl<C> Pack for NonceCommitment<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+{
+    type Packed = [u8; 32];
+    type Error = Error<C>;
+
+    fn pack(&self) -> [u8; 32] {
+        pack_element(&self.serialize())
+    }
+
+    fn unpack(packed: &[u8; 32]) -> Result<Self, Error<C>> {
+        Self::deserialize(unpack_element(packed)?)
+    }
+}
+
+impl<C> Pack for Signature<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup, SignatureSerialization = [u8; 64]>,
+{
+    type Packed = [u8; 64];
+    type Error = Error<C>;
+
+    fn pack(&self) -> [u8; 64] {
+        let mut packed = self.serialize();
+        let mut R = [0u8; 32];
+        R.copy_from_slice(&packed[..32]);
+        packed[..32].copy_from_slice(&pack_element(&R));
+        packed
+    }
+
+    fn unpack(packed: &[u8; 64]) -> Result<Self, Error<C>> {
+        let mut R = [0u8; 32];
+        R.copy_from_slice(&packed[..32]);
+        let R = unpack_element(&R).map_err(|_| Error::MalformedSignature)?;
+
+        // Scalar deserialization reduces modulo the group order, so reject
+        // non-canonical `S` here.
+        let S = &packed[32..];
+        if Fr::from_le_bytes_mod_order(S).into_bigint().to_bytes_le() != S {
+            return Err(Error::MalformedSignature);
+        }
+
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&R);
+        bytes[32..].copy_from_slice(S);
+        Self::deserialize(bytes)
+    }
+}
+
+/// A serde adapter for [`Pack`] types, to be used with
+/// `#[serde(with = "frost_bjj::pack::hex")]`.
+///
+/// Values are serialized as lowercase hex strings of their packed encoding,
+/// without a `0x` prefix.
+#[cfg(feature = "serde")]
+pub mod hex {
+    use frost_core::serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+    use super::*;
+
+    /// Serializes `value` as the hex string of its packed encoding.
+    pub fn serialize<T: Pack, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&::hex::encode(value.pack()))
+    }
+
+    /// Deserializes a value from the hex string of its packed encoding.
+    pub fn deserialize<'de, T: Pack, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        let bytes = ::hex::decode(string).map_err(D::Error::custom)?;
+        let packed = T::Packed::try_from(&bytes[..])
+            .map_err(|_| D::Error::invalid_length(bytes.len(), &"a packed point or signature"))?;
+        T::unpack(&packed).map_err(D::Error::custom)
+    }
+}
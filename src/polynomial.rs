@@ -0,0 +1,96 @@
+//! Polynomial and verifiable secret sharing helpers shared by the key
+//! management protocols.
+
+use frost_core::{
+    frost::{keys::VerifiableSecretSharingCommitment, Identifier},
+    Ciphersuite, Element, Error, Field, Group, Scalar,
+};
+use rand_core::{CryptoRng, RngCore};
+
+/// Returns the scalar value of `identifier`.
+pub(crate) fn identifier_scalar<C: Ciphersuite>(identifier: &Identifier<C>) -> Scalar<C> {
+    <<C::Group as Group>::Field>::deserialize(&identifier.serialize())
+        .expect("identifiers are valid scalars")
+}
+
+/// Checks the number of signers like frost-core's key generation.
+pub(crate) fn validate_num_of_signers<C: Ciphersuite>(
+    min_signers: u16,
+    max_signers: u16,
+) -> Result<(), Error<C>> {
+    if min_signers < 2 {
+        return Err(Error::InvalidMinSigners);
+    }
+    if max_signers < 2 {
+        return Err(Error::InvalidMaxSigners);
+    }
+    if min_signers > max_signers {
+        return Err(Error::InvalidMinSigners);
+    }
+    Ok(())
+}
+
+/// Returns `n` random scalars.
+pub(crate) fn random_coefficients<C, R>(n: usize, rng: &mut R) -> Vec<Scalar<C>>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    (0..n)
+        .map(|_| <<C::Group as Group>::Field>::random(rng))
+        .collect()
+}
+
+/// Evaluates the polynomial `constant + sum(coefficients[k] * x^(k + 1))` at
+/// `identifier`.
+pub(crate) fn evaluate_polynomial<C: Ciphersuite>(
+    identifier: &Identifier<C>,
+    constant: Scalar<C>,
+    coefficients: &[Scalar<C>],
+) -> Scalar<C> {
+    let x = identifier_scalar(identifier);
+    let mut value = <<C::Group as Group>::Field>::zero();
+    for coefficient in coefficients.iter().rev() {
+        value = (value + *coefficient) * x;
+    }
+    value + constant
+}
+
+/// Returns the commitments to `coefficients`.
+pub(crate) fn commit<C: Ciphersuite>(
+    coefficients: &[Scalar<C>],
+) -> Result<VerifiableSecretSharingCommitment<C>, Error<C>> {
+    VerifiableSecretSharingCommitment::deserialize(
+        coefficients
+            .iter()
+            .map(|coefficient| <C::Group>::serialize(&(<C::Group>::generator() * *coefficient)))
+            .collect(),
+    )
+}
+
+/// Returns the group elements of `commitment`.
+pub(crate) fn commitment_elements<C: Ciphersuite>(
+    commitment: &VerifiableSecretSharingCommitment<C>,
+) -> Vec<Element<C>> {
+    commitment
+        .serialize()
+        .iter()
+        .map(|element| <C::Group>::deserialize(element).expect("commitments hold valid elements"))
+        .collect()
+}
+
+/// Evaluates the commitments to the polynomial
+/// `constant + sum(coefficients[k] * x^(k + 1))` at `identifier`, where
+/// `constant` and `coefficients` are the commitments to the coefficients.
+pub(crate) fn evaluate_commitment<C: Ciphersuite>(
+    identifier: &Identifier<C>,
+    constant: Element<C>,
+    coefficients: &[Element<C>],
+) -> Element<C> {
+    let x = identifier_scalar(identifier);
+    let mut value = <C::Group>::identity();
+    for coefficient in coefficients.iter().rev() {
+        value = (value + *coefficient) * x;
+    }
+    value + constant
+}
@@ -0,0 +1,139 @@
+//! The FROST(babyjubjub, Poseidon) ciphersuite.
+//!
+//! All hashes are built on the circomlib Poseidon permutation over the BN254
+//! scalar field, which is the base field `Fq` of BabyJubJub. This makes the
+//! signature challenge cheap to recompute inside a SNARK.
+
+use ark_ec::twisted_edwards::Affine;
+use ark_ed_on_bn254::{Fq, Fr};
+use ark_ff::{BigInteger, PrimeField, Zero};
+use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+use light_poseidon::{Poseidon, PoseidonHasher};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+use crate::babyjubjub::EdwardsConfig;
+use crate::{babyjubjub_ciphersuite, frost_api, BabyJubJubHash};
+
+/// Context string for the FROST(babyjubjub, Poseidon) ciphersuite.
+const CONTEXT_STRING: &str = "FROST-babyjubjub-Poseidon-v1";
+
+/// Number of message bytes packed into a single field element.
+///
+/// 31 bytes always fit below the BN254 scalar field modulus.
+const BYTES_PER_ELEMENT: usize = 31;
+
+/// Hashes `inputs` with the circomlib Poseidon permutation of matching width.
+///
+/// Panics if more than 12 inputs are given, which is the widest instance with
+/// circomlib parameters.
+pub(crate) fn poseidon(inputs: &[Fq]) -> Fq {
+    Poseidon::<Fq>::new_circom(inputs.len())
+        .and_then(|mut hasher| hasher.hash(inputs))
+        .expect("inputs must fit a circomlib Poseidon instance")
+}
+
+/// Packs bytes into field elements, [`BYTES_PER_ELEMENT`] little-endian bytes
+/// per element.
+fn bytes_to_fields(bytes: &[u8]) -> impl Iterator<Item = Fq> + '_ {
+    bytes
+        .chunks(BYTES_PER_ELEMENT)
+        .map(Fq::from_le_bytes_mod_order)
+}
+
+/// Hashes `msg` with domain separation `domain` to a base field element.
+///
+/// The domain and the message are each prefixed with their byte length, packed
+/// into field elements and absorbed one element at a time with the two-input
+/// Poseidon permutation, starting from zero:
+/// `h_0 = 0`, `h_{i+1} = Poseidon(h_i, x_i)`.
+pub(crate) fn hash_to_field(domain: &[u8], msg: &[u8]) -> Fq {
+    core::iter::once(Fq::from(domain.len() as u64))
+        .chain(bytes_to_fields(domain))
+        .chain(core::iter::once(Fq::from(msg.len() as u64)))
+        .chain(bytes_to_fields(msg))
+        .fold(Fq::zero(), |h, x| poseidon(&[h, x]))
+}
+
+/// Reduces a base field element into the scalar field.
+///
+/// The base field modulus is less than `2^126` below eight times the scalar
+/// field modulus, so the bias of the reduction is below `2^-127`.
+pub(crate) fn field_to_scalar(element: Fq) -> Fr {
+    Fr::from_le_bytes_mod_order(&element.into_bigint().to_bytes_le())
+}
+
+pub(crate) fn hash_to_scalar(domain: &[u8], msg: &[u8]) -> Fr {
+    field_to_scalar(hash_to_field(domain, msg))
+}
+
+pub(crate) fn hash_to_array(domain: &[u8], msg: &[u8]) -> [u8; 32] {
+    let bytes = hash_to_field(domain, msg).into_bigint().to_bytes_le();
+    let mut output = [0u8; 32];
+    output.copy_from_slice(&bytes);
+    output
+}
+
+/// Decodes a serialized point, accepting the identity.
+pub(crate) fn decode_point(bytes: &[u8]) -> Option<Affine<EdwardsConfig>> {
+    Affine::<EdwardsConfig>::deserialize_with_mode(bytes, Compress::Yes, Validate::Yes).ok()
+}
+
+/// Hashes the message bytes to the field element `m` of the challenge.
+pub(crate) fn challenge_message(msg: &[u8]) -> Fq {
+    hash_to_field((CONTEXT_STRING.to_owned() + "chal").as_bytes(), msg)
+}
+
+/// Computes the FROST(babyjubjub, Poseidon) challenge from the affine
+/// coordinates of `R` and `A` and the message.
+///
+/// `c = Poseidon(R.x, R.y, A.x, A.y, m)` reduced into the scalar field, where
+/// `m` is the domain-separated [`hash_to_field`] of the message bytes.
+pub(crate) fn challenge(
+    R: &Affine<EdwardsConfig>,
+    verifying_key: &Affine<EdwardsConfig>,
+    msg: &[u8],
+) -> Fr {
+    let m = challenge_message(msg);
+    field_to_scalar(poseidon(&[R.x, R.y, verifying_key.x, verifying_key.y, m]))
+}
+
+/// An implementation of the FROST(babyjubjub, Poseidon) ciphersuite.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+pub struct BabyJubJubPoseidon;
+
+impl BabyJubJubHash for BabyJubJubPoseidon {
+    const ID: &'static str = "FROST(babyjubjub, Poseidon)";
+
+    const CONTEXT_STRING: &'static str = CONTEXT_STRING;
+
+    fn hash_to_scalar(domain: &[u8], msg: &[u8]) -> Fr {
+        hash_to_scalar(domain, msg)
+    }
+
+    fn hash_to_array(domain: &[u8], msg: &[u8]) -> [u8; 32] {
+        hash_to_array(domain, msg)
+    }
+
+    /// frost-core always calls this with `R || A || msg`. The two points are
+    /// decoded and hashed by their affine coordinates so that
+    /// circuits never need to handle the compressed encoding. Any other input
+    /// is hashed as plain bytes.
+    fn challenge(m: &[u8]) -> Fr {
+        let points = m
+            .get(..32)
+            .and_then(decode_point)
+            .zip(m.get(32..64).and_then(decode_point));
+        match points {
+            Some((R, A)) => challenge(&R, &A, &m[64..]),
+            None => hash_to_scalar((CONTEXT_STRING.to_owned() + "chal").as_bytes(), m),
+        }
+    }
+}
+
+babyjubjub_ciphersuite!(BabyJubJubPoseidon);
+
+frost_api!(BabyJubJubPoseidon, "FROST(babyjubjub, Poseidon)");
@@ -0,0 +1,357 @@
+//! R1CS gadgets to verify FROST signatures inside arkworks circuits.
+//!
+//! The constraint field is the BabyJubJub base field `Fq`, i.e. the BN254
+//! scalar field, so these gadgets fit Groth16 or any other BN254 proof system.
+//!
+//! The ciphersuites implementing [`CircuitCiphersuite`] are
+//! [`BabyJubJubPoseidon`] and [`BabyJubJubCircomlib`], whose message enters
+//! the circuit as a single field element, usually a public input, and
+//! [`BabyJubJubSha256`], whose message enters the circuit as bytes. The
+//! SHA-256 challenge hashes the compressed encodings of `R` and `A` and the
+//! whole message, which costs tens of thousands of constraints per 64-byte
+//! block, so signatures that must be proven cheaply should be made with a
+//! Poseidon ciphersuite. [`BabyJubJubKeccak256`] is not supported.
+//!
+//! [`BabyJubJubKeccak256`]: crate::BabyJubJubKeccak256
+//!
+//! [`VerifyingKeyVar::verify`] checks `z * B == R + c * A`. frost-core checks
+//! the same equation multiplied by the cofactor, which is equivalent because
+//! all points involved are in the prime-order subgroup: the verifying key
+//! because [`VerifyingKey`] values are validated when deserialized, and `R`
+//! because witnesses are checked to be in the subgroup when allocated. Points
+//! allocated as public inputs are only checked to be on the curve, so the
+//! verifier must make sure they come from a valid [`VerifyingKey`].
+
+use core::{borrow::Borrow, marker::PhantomData};
+
+use ark_crypto_primitives::crh::sha256::constraints::Sha256Gadget;
+use ark_ed_on_bn254::constraints::FqVar;
+use ark_ff::PrimeField;
+use ark_r1cs_std::{groups::curves::twisted_edwards::AffineVar, prelude::*};
+use ark_relations::r1cs::{Namespace, SynthesisError};
+use frost_core::{Ciphersuite, Signature, VerifyingKey};
+use light_poseidon::parameters::bn254_x5::get_poseidon_parameters;
+
+use crate::babyjubjub::{EdwardsConfig, EdwardsProjective};
+use crate::poseidon::decode_point;
+use crate::{
+    BabyJubJubCircomlib, BabyJubJubGroup, BabyJubJubHash, BabyJubJubPoseidon, BabyJubJubSha256, Fq,
+    Group,
+};
+
+/// A BabyJubJub point in a circuit.
+pub type PointVar = AffineVar<EdwardsConfig, FqVar>;
+
+/// Hashes `inputs` with the circomlib Poseidon permutation of matching width,
+/// like the native hash used by the Poseidon ciphersuites.
+pub fn poseidon(inputs: &[FqVar]) -> Result<FqVar, SynthesisError> {
+    let width = inputs.len() + 1;
+    let params = u8::try_from(width)
+        .ok()
+        .and_then(|width| get_poseidon_parameters::<Fq>(width).ok())
+        .ok_or(SynthesisError::Unsatisfiable)?;
+
+    let mut state: Vec<FqVar> = core::iter::once(FqVar::zero())
+        .chain(inputs.iter().cloned())
+        .collect();
+
+    let half_rounds = params.full_rounds / 2;
+    let all_rounds = params.full_rounds + params.partial_rounds;
+    for round in 0..all_rounds {
+        for (i, element) in state.iter_mut().enumerate() {
+            *element += params.ark[round * width + i];
+        }
+
+        let full = round < half_rounds || round >= half_rounds + params.partial_rounds;
+        for element in state.iter_mut().take(if full { width } else { 1 }) {
+            *element = element.pow_by_constant([params.alpha])?;
+        }
+
+        state = params
+            .mds
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(&state)
+                    .fold(FqVar::zero(), |acc, (m, element)| acc + element * *m)
+            })
+            .collect();
+    }
+
+    Ok(state.swap_remove(0))
+}
+
+/// Ciphersuites whose signatures can be verified with [`VerifyingKeyVar`].
+///
+/// See the [module documentation](self) for the implementations.
+pub trait CircuitCiphersuite: Ciphersuite<Group = BabyJubJubGroup> {
+    /// The value the challenge takes the message as in the circuit.
+    type Message;
+
+    /// [`Self::Message`] in a circuit.
+    type MessageVar: AllocVar<Self::Message, Fq>;
+
+    /// Maps the message signed with FROST to the value the challenge is
+    /// computed from in the circuit.
+    fn message(msg: &[u8]) -> Self::Message;
+
+    /// Computes `c * A` for the challenge `c` of the commitment `R`, the
+    /// verifying key `A` and the message `m`.
+    fn challenge_times_key(
+        R: &PointVar,
+        verifying_key: &PointVar,
+        m: &Self::MessageVar,
+    ) -> Result<PointVar, SynthesisError>;
+}
+
+/// Hashes the affine coordinates of `R` and `A` and the message.
+fn hash_points(R: &PointVar, verifying_key: &PointVar, m: &FqVar) -> Result<FqVar, SynthesisError> {
+    poseidon(&[
+        R.x.clone(),
+        R.y.clone(),
+        verifying_key.x.clone(),
+        verifying_key.y.clone(),
+        m.clone(),
+    ])
+}
+
+impl CircuitCiphersuite for BabyJubJubPoseidon {
+    type Message = Fq;
+
+    type MessageVar = FqVar;
+
+    /// The domain-separated hash of the message computed by the native
+    /// challenge.
+    fn message(msg: &[u8]) -> Fq {
+        crate::poseidon::challenge_message(msg)
+    }
+
+    /// The challenge is the hash reduced into the scalar field, which
+    /// multiplies points of the prime-order subgroup like the hash itself.
+    fn challenge_times_key(
+        R: &PointVar,
+        verifying_key: &PointVar,
+        m: &FqVar,
+    ) -> Result<PointVar, SynthesisError> {
+        let c = hash_points(R, verifying_key, m)?;
+        verifying_key.scalar_mul_le(c.to_bits_le()?.iter())
+    }
+}
+
+impl CircuitCiphersuite for BabyJubJubCircomlib {
+    type Message = Fq;
+
+    type MessageVar = FqVar;
+
+    /// See [`crate::circomlib::message_to_field`].
+    fn message(msg: &[u8]) -> Fq {
+        crate::circomlib::message_to_field(msg)
+    }
+
+    /// Computes `h * (8 * A)` like circomlib's `EdDSAPoseidonVerifier`.
+    fn challenge_times_key(
+        R: &PointVar,
+        verifying_key: &PointVar,
+        m: &FqVar,
+    ) -> Result<PointVar, SynthesisError> {
+        let h = hash_points(R, verifying_key, m)?;
+        let key_times_8 = verifying_key.double()?.double()?.double()?;
+        key_times_8.scalar_mul_le(h.to_bits_le()?.iter())
+    }
+}
+
+/// A message in a circuit, as bytes.
+pub struct MessageBytesVar(pub Vec<UInt8<Fq>>);
+
+impl AllocVar<Vec<u8>, Fq> for MessageBytesVar {
+    fn new_variable<T: Borrow<Vec<u8>>>(
+        cs: impl Into<Namespace<Fq>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let bytes = Vec::new_variable(cs, || f().map(|msg| msg.borrow().clone()), mode)?;
+        Ok(MessageBytesVar(bytes))
+    }
+}
+
+/// Returns the compressed encoding of `point`, like [`Group::serialize`]:
+/// `y` in little-endian with the top bit set if `x` is negative, i.e. greater
+/// than `-x`.
+pub fn serialize_point(point: &PointVar) -> Result<Vec<UInt8<Fq>>, SynthesisError> {
+    // x > -x exactly when 2x wraps around the odd modulus, which makes the
+    // canonical 2x odd.
+    let is_negative = point.x.double()?.to_bits_le()?.swap_remove(0);
+    let mut bits = point.y.to_bits_le()?;
+    bits.resize(255, Boolean::FALSE);
+    bits.push(is_negative);
+    Ok(bits.chunks(8).map(UInt8::from_bits_le).collect())
+}
+
+/// Computes the SHA-256 challenge of [`BabyJubJubSha256`] as little-endian
+/// bits.
+///
+/// The native challenge is the first element of arkworks' `hash_to_field`
+/// with `expand_message_xmd` over `R || A || msg`: the big-endian integer
+/// `b_1 || b_2[..16]` reduced modulo the group order. The reduction is
+/// skipped, since the integer multiplies points of the prime-order subgroup
+/// like its reduction.
+fn sha256_challenge(
+    R: &PointVar,
+    verifying_key: &PointVar,
+    msg: &[UInt8<Fq>],
+) -> Result<Vec<Boolean<Fq>>, SynthesisError> {
+    // `hash_to_field(msg, 32)` expands to 32 elements of 48 bytes, and
+    // arkworks 0.4 pads with 48 zero bytes rather than the SHA-256 block size.
+    const LEN_PER_ELEMENT: usize = 48;
+    let len_in_bytes = (32 * LEN_PER_ELEMENT as u16).to_be_bytes();
+    let mut dst_prime = (BabyJubJubSha256::CONTEXT_STRING.to_owned() + "chal").into_bytes();
+    dst_prime.push(dst_prime.len() as u8);
+    let dst_prime = UInt8::constant_vec(&dst_prime);
+
+    let mut b_0 = Sha256Gadget::default();
+    b_0.update(&UInt8::constant_vec(&[0; LEN_PER_ELEMENT]))?;
+    b_0.update(&serialize_point(R)?)?;
+    b_0.update(&serialize_point(verifying_key)?)?;
+    b_0.update(msg)?;
+    b_0.update(&UInt8::constant_vec(&[len_in_bytes[0], len_in_bytes[1], 0]))?;
+    b_0.update(&dst_prime)?;
+    let b_0 = b_0.finalize()?.0;
+
+    let b_1 = Sha256Gadget::digest(&[&b_0[..], &[UInt8::constant(1)], &dst_prime].concat())?.0;
+    let b_0_xor_b_1 = b_0
+        .iter()
+        .zip(&b_1)
+        .map(|(b_0, b_1)| b_0.xor(b_1))
+        .collect::<Result<Vec<_>, _>>()?;
+    let b_2 =
+        Sha256Gadget::digest(&[&b_0_xor_b_1[..], &[UInt8::constant(2)], &dst_prime].concat())?.0;
+
+    let mut bits = Vec::new();
+    for byte in b_1.iter().chain(&b_2[..LEN_PER_ELEMENT - 32]).rev() {
+        bits.extend(byte.to_bits_le()?);
+    }
+    Ok(bits)
+}
+
+impl CircuitCiphersuite for BabyJubJubSha256 {
+    type Message = Vec<u8>;
+
+    type MessageVar = MessageBytesVar;
+
+    /// The message bytes, hashed whole in the circuit.
+    fn message(msg: &[u8]) -> Vec<u8> {
+        msg.to_vec()
+    }
+
+    /// Computes the challenge with SHA-256 like [`BabyJubJubHash::challenge`].
+    fn challenge_times_key(
+        R: &PointVar,
+        verifying_key: &PointVar,
+        m: &MessageBytesVar,
+    ) -> Result<PointVar, SynthesisError> {
+        let c = sha256_challenge(R, verifying_key, &m.0)?;
+        verifying_key.scalar_mul_le(c.iter())
+    }
+}
+
+/// Decodes the default serialization of a group element.
+fn element(bytes: &[u8]) -> EdwardsProjective {
+    decode_point(bytes)
+        .expect("group elements are valid points")
+        .into()
+}
+
+/// A [`VerifyingKey`] in a circuit.
+pub struct VerifyingKeyVar<C> {
+    /// The verifying key point.
+    pub point: PointVar,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> AllocVar<VerifyingKey<C>, Fq> for VerifyingKeyVar<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+{
+    fn new_variable<T: Borrow<VerifyingKey<C>>>(
+        cs: impl Into<Namespace<Fq>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let point = PointVar::new_variable(
+            cs,
+            || f().map(|key| element(&key.borrow().serialize())),
+            mode,
+        )?;
+        Ok(VerifyingKeyVar {
+            point,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// A [`Signature`] in a circuit.
+///
+/// The response `z` is allocated as a base field element, which holds any
+/// scalar since the scalar field is smaller than the base field.
+pub struct SignatureVar<C> {
+    /// The commitment `R`.
+    pub R: PointVar,
+    /// The response `z`.
+    pub z: FqVar,
+    _phantom: PhantomData<C>,
+}
+
+impl<C> AllocVar<Signature<C>, Fq> for SignatureVar<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup, SignatureSerialization = [u8; 64]>,
+{
+    fn new_variable<T: Borrow<Signature<C>>>(
+        cs: impl Into<Namespace<Fq>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+
+        let bytes = f().map(|signature| signature.borrow().serialize());
+        let R = PointVar::new_variable(
+            ark_relations::ns!(cs, "R"),
+            || bytes.map(|bytes| element(&bytes[..32])),
+            mode,
+        )?;
+        let z = FqVar::new_variable(
+            ark_relations::ns!(cs, "z"),
+            || bytes.map(|bytes| Fq::from_le_bytes_mod_order(&bytes[32..])),
+            mode,
+        )?;
+        Ok(SignatureVar {
+            R,
+            z,
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl<C: CircuitCiphersuite> VerifyingKeyVar<C> {
+    /// Returns whether `signature` is valid for the message `m` under this
+    /// key.
+    pub fn verify(
+        &self,
+        m: &C::MessageVar,
+        signature: &SignatureVar<C>,
+    ) -> Result<Boolean<Fq>, SynthesisError> {
+        let generator = PointVar::constant(BabyJubJubGroup::generator());
+        let left = generator.scalar_mul_le(signature.z.to_bits_le()?.iter())?;
+        let right = signature.R.clone() + C::challenge_times_key(&signature.R, &self.point, m)?;
+        left.is_eq(&right)
+    }
+
+    /// Enforces that `signature` is valid for the message `m` under this key.
+    pub fn enforce_verify(
+        &self,
+        m: &C::MessageVar,
+        signature: &SignatureVar<C>,
+    ) -> Result<(), SynthesisError> {
+        self.verify(m, signature)?.enforce_equal(&Boolean::TRUE)
+    }
+}
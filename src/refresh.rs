@@ -0,0 +1,515 @@
+//! Proactive refresh of key shares.
+//!
+//! Refreshing adds to every signing share the evaluation of a random
+//! polynomial whose constant term is zero. The group secret, and thus the
+//! group [`VerifyingKey`](frost_core::VerifyingKey), are unchanged, while
+//! every [`SigningShare`] and [`VerifyingShare`] is re-randomized: shares
+//! from before and after a refresh do not combine into the group secret, so
+//! an attacker must compromise `min_signers` participants between two
+//! refreshes.
+//!
+//! The polynomial is generated either by a trusted dealer with
+//! [`compute_refreshing_shares`], or by all the participants together with
+//! the [`dkg`] functions. Every participant must take part in a refresh, and
+//! must then discard its old [`KeyPackage`].
+//!
+//! The commitments to the polynomial omit the constant term, which makes it
+//! zero by construction.
+
+use std::collections::HashMap;
+
+use frost_core::{
+    frost::{
+        keys::{
+            KeyPackage, PublicKeyPackage, SigningShare, VerifiableSecretSharingCommitment,
+            VerifyingShare,
+        },
+        Identifier,
+    },
+    Ciphersuite, Element, Error, Field, Group, Scalar,
+};
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+use crate::polynomial::{
+    commit, commitment_elements, evaluate_commitment, evaluate_polynomial, random_coefficients,
+    validate_num_of_signers,
+};
+
+/// A share of zero that refreshes the key share of a participant, generated
+/// by [`compute_refreshing_shares`].
+///
+/// # Security
+///
+/// The refreshing share must be sent over a confidential and authenticated
+/// channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "C: Ciphersuite + serde::Serialize",
+        deserialize = "C: Ciphersuite + serde::Deserialize<'de>"
+    ))
+)]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct RefreshingShare<C: Ciphersuite> {
+    identifier: Identifier<C>,
+    value: SigningShare<C>,
+    commitment: VerifiableSecretSharingCommitment<C>,
+}
+
+impl<C: Ciphersuite> RefreshingShare<C> {
+    /// Creates a new [`RefreshingShare`].
+    pub fn new(
+        identifier: Identifier<C>,
+        value: SigningShare<C>,
+        commitment: VerifiableSecretSharingCommitment<C>,
+    ) -> Self {
+        Self {
+            identifier,
+            value,
+            commitment,
+        }
+    }
+
+    /// The identifier of the participant whose share is refreshed.
+    pub fn identifier(&self) -> &Identifier<C> {
+        &self.identifier
+    }
+
+    /// The value added to the signing share.
+    pub fn value(&self) -> &SigningShare<C> {
+        &self.value
+    }
+
+    /// The commitments to the non-constant coefficients of the refreshing
+    /// polynomial.
+    pub fn commitment(&self) -> &VerifiableSecretSharingCommitment<C> {
+        &self.commitment
+    }
+
+    /// Checks the share against its commitment.
+    pub fn verify(&self) -> Result<(), Error<C>> {
+        verify_share(&self.identifier, &self.value, &self.commitment)
+    }
+}
+
+/// Checks that `value` is the evaluation at `identifier` of the zero-constant
+/// polynomial committed to by `commitment`.
+fn verify_share<C: Ciphersuite>(
+    identifier: &Identifier<C>,
+    value: &SigningShare<C>,
+    commitment: &VerifiableSecretSharingCommitment<C>,
+) -> Result<(), Error<C>> {
+    let expected = evaluate_commitment(
+        identifier,
+        <C::Group>::identity(),
+        &commitment_elements(commitment),
+    );
+    if <C::Group>::generator() * value.to_scalar() == expected {
+        Ok(())
+    } else {
+        Err(Error::InvalidSecretShare)
+    }
+}
+
+/// Adds the evaluations of the zero-constant `commitments` to every
+/// verifying share of `pubkeys`.
+fn refresh_pubkeys<C: Ciphersuite>(
+    pubkeys: &PublicKeyPackage<C>,
+    commitments: &[Vec<Element<C>>],
+) -> PublicKeyPackage<C> {
+    let signer_pubkeys = pubkeys
+        .signer_pubkeys()
+        .iter()
+        .map(|(identifier, verifying_share)| {
+            let delta = commitments.iter().fold(<C::Group>::identity(), |sum, c| {
+                sum + evaluate_commitment(identifier, <C::Group>::identity(), c)
+            });
+            (
+                *identifier,
+                VerifyingShare::new(verifying_share.to_element() + delta),
+            )
+        })
+        .collect();
+    PublicKeyPackage::new(signer_pubkeys, *pubkeys.group_public())
+}
+
+/// Adds `delta` to the signing share of `key_package`.
+fn refresh_key_package<C: Ciphersuite>(
+    key_package: &KeyPackage<C>,
+    delta: Scalar<C>,
+) -> KeyPackage<C> {
+    let signing_share = SigningShare::new(key_package.secret_share().to_scalar() + delta);
+    KeyPackage::new(
+        *key_package.identifier(),
+        signing_share,
+        VerifyingShare::from(signing_share),
+        *key_package.group_public(),
+        *key_package.min_signers(),
+    )
+}
+
+/// Generates the refreshing shares of all the participants of `pubkeys` as a
+/// trusted dealer, and the refreshed [`PublicKeyPackage`].
+///
+/// `min_signers` must be the threshold the keys were generated with. Each
+/// participant then calls [`refresh_share`] with its [`RefreshingShare`].
+#[allow(clippy::type_complexity)]
+pub fn compute_refreshing_shares<C, R>(
+    pubkeys: &PublicKeyPackage<C>,
+    min_signers: u16,
+    rng: &mut R,
+) -> Result<
+    (
+        HashMap<Identifier<C>, RefreshingShare<C>>,
+        PublicKeyPackage<C>,
+    ),
+    Error<C>,
+>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    let max_signers =
+        u16::try_from(pubkeys.signer_pubkeys().len()).map_err(|_| Error::InvalidMaxSigners)?;
+    validate_num_of_signers::<C>(min_signers, max_signers)?;
+
+    let coefficients = random_coefficients::<C, R>(min_signers as usize - 1, rng);
+    let commitment = commit(&coefficients)?;
+    let zero = <<C::Group as Group>::Field>::zero();
+
+    let refreshing_shares = pubkeys
+        .signer_pubkeys()
+        .keys()
+        .map(|identifier| {
+            let value = evaluate_polynomial(identifier, zero, &coefficients);
+            (
+                *identifier,
+                RefreshingShare::new(*identifier, SigningShare::new(value), commitment.clone()),
+            )
+        })
+        .collect();
+    let pubkeys = refresh_pubkeys(pubkeys, &[commitment_elements(&commitment)]);
+
+    Ok((refreshing_shares, pubkeys))
+}
+
+/// Refreshes `key_package` with the [`RefreshingShare`] received from the
+/// dealer, after checking it.
+pub fn refresh_share<C: Ciphersuite>(
+    refreshing_share: RefreshingShare<C>,
+    key_package: &KeyPackage<C>,
+) -> Result<KeyPackage<C>, Error<C>> {
+    if refreshing_share.identifier != *key_package.identifier() {
+        return Err(Error::UnknownIdentifier);
+    }
+    if refreshing_share.commitment.serialize().len() != *key_package.min_signers() as usize - 1 {
+        return Err(Error::IncorrectNumberOfCommitments);
+    }
+    refreshing_share.verify()?;
+
+    Ok(refresh_key_package(
+        key_package,
+        refreshing_share.value.to_scalar(),
+    ))
+}
+
+pub mod dkg {
+    //! Distributed refresh, without a trusted dealer.
+    //!
+    //! Every participant calls [`part1`], [`part2`] and [`part3`] in turn, like
+    //! the distributed key generation. The [`round1::Package`] is broadcast to
+    //! every other participant, while each [`round2::Package`] is sent to its
+    //! recipient over a confidential and authenticated channel. The refreshed
+    //! [`PublicKeyPackage`] returned by [`part3`] is the same for every
+    //! participant.
+
+    use super::*;
+
+    /// Refresh round 1 structures.
+    pub mod round1 {
+        use super::*;
+
+        /// The package that must be broadcast by each participant to all
+        /// other participants in round 1.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(
+            feature = "serde",
+            serde(bound(
+                serialize = "C: Ciphersuite + serde::Serialize",
+                deserialize = "C: Ciphersuite + serde::Deserialize<'de>"
+            ))
+        )]
+        #[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+        #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+        pub struct Package<C: Ciphersuite> {
+            pub(super) commitment: VerifiableSecretSharingCommitment<C>,
+        }
+
+        impl<C: Ciphersuite> Package<C> {
+            /// Creates a new [`Package`].
+            pub fn new(commitment: VerifiableSecretSharingCommitment<C>) -> Self {
+                Self { commitment }
+            }
+
+            /// The commitments to the non-constant coefficients of the
+            /// participant's refreshing polynomial.
+            pub fn commitment(&self) -> &VerifiableSecretSharingCommitment<C> {
+                &self.commitment
+            }
+        }
+
+        /// The secret package that must be kept in memory by the participant
+        /// between [`part1`] and [`part2`].
+        ///
+        /// # Security
+        ///
+        /// This package MUST NOT be sent to other participants!
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct SecretPackage<C: Ciphersuite> {
+            pub(super) identifier: Identifier<C>,
+            pub(super) coefficients: Vec<Scalar<C>>,
+            pub(super) commitment: VerifiableSecretSharingCommitment<C>,
+            pub(super) min_signers: u16,
+            pub(super) max_signers: u16,
+        }
+
+        impl<C: Ciphersuite> core::fmt::Debug for SecretPackage<C> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("SecretPackage")
+                    .field("identifier", &self.identifier)
+                    .field("coefficients", &"<redacted>")
+                    .field("commitment", &self.commitment)
+                    .field("min_signers", &self.min_signers)
+                    .field("max_signers", &self.max_signers)
+                    .finish()
+            }
+        }
+    }
+
+    /// Refresh round 2 structures.
+    pub mod round2 {
+        use super::*;
+
+        /// A package that must be sent by each participant to one other
+        /// participant in round 2.
+        ///
+        /// # Security
+        ///
+        /// The package must be sent on a confidential and authenticated
+        /// channel.
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(
+            feature = "serde",
+            serde(bound(
+                serialize = "C: Ciphersuite + serde::Serialize",
+                deserialize = "C: Ciphersuite + serde::Deserialize<'de>"
+            ))
+        )]
+        #[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+        #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+        pub struct Package<C: Ciphersuite> {
+            pub(super) refreshing_share: SigningShare<C>,
+        }
+
+        impl<C: Ciphersuite> Package<C> {
+            /// Creates a new [`Package`].
+            pub fn new(refreshing_share: SigningShare<C>) -> Self {
+                Self { refreshing_share }
+            }
+
+            /// The evaluation of the sender's refreshing polynomial at the
+            /// recipient's identifier.
+            pub fn refreshing_share(&self) -> &SigningShare<C> {
+                &self.refreshing_share
+            }
+        }
+
+        /// The secret package that must be kept in memory by the participant
+        /// between [`part2`] and [`part3`].
+        ///
+        /// # Security
+        ///
+        /// This package MUST NOT be sent to other participants!
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct SecretPackage<C: Ciphersuite> {
+            pub(super) identifier: Identifier<C>,
+            pub(super) commitment: VerifiableSecretSharingCommitment<C>,
+            pub(super) refreshing_share: Scalar<C>,
+            pub(super) min_signers: u16,
+            pub(super) max_signers: u16,
+        }
+
+        impl<C: Ciphersuite> core::fmt::Debug for SecretPackage<C> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct("SecretPackage")
+                    .field("identifier", &self.identifier)
+                    .field("commitment", &self.commitment)
+                    .field("refreshing_share", &"<redacted>")
+                    .field("min_signers", &self.min_signers)
+                    .field("max_signers", &self.max_signers)
+                    .finish()
+            }
+        }
+    }
+
+    /// Performs the first part of the distributed refresh for the given
+    /// participant.
+    ///
+    /// `max_signers` is the number of participants, all of which must take
+    /// part, and `min_signers` the threshold the keys were generated with.
+    pub fn part1<C, R>(
+        identifier: Identifier<C>,
+        max_signers: u16,
+        min_signers: u16,
+        mut rng: R,
+    ) -> Result<(round1::SecretPackage<C>, round1::Package<C>), Error<C>>
+    where
+        C: Ciphersuite,
+        R: RngCore + CryptoRng,
+    {
+        validate_num_of_signers::<C>(min_signers, max_signers)?;
+
+        let coefficients = random_coefficients::<C, R>(min_signers as usize - 1, &mut rng);
+        let commitment = commit(&coefficients)?;
+        Ok((
+            round1::SecretPackage {
+                identifier,
+                coefficients,
+                commitment: commitment.clone(),
+                min_signers,
+                max_signers,
+            },
+            round1::Package::new(commitment),
+        ))
+    }
+
+    /// Performs the second part of the distributed refresh, given the
+    /// [`round1::Package`]s received from the other participants.
+    ///
+    /// It returns the [`round2::SecretPackage`] to keep for [`part3`] and the
+    /// [`round2::Package`]s to send to the other participants.
+    #[allow(clippy::type_complexity)]
+    pub fn part2<C: Ciphersuite>(
+        secret_package: round1::SecretPackage<C>,
+        round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+    ) -> Result<
+        (
+            round2::SecretPackage<C>,
+            HashMap<Identifier<C>, round2::Package<C>>,
+        ),
+        Error<C>,
+    > {
+        if round1_packages.len() != secret_package.max_signers as usize - 1 {
+            return Err(Error::IncorrectNumberOfPackages);
+        }
+
+        let zero = <<C::Group as Group>::Field>::zero();
+        let mut round2_packages = HashMap::new();
+        for (identifier, package) in round1_packages {
+            if *identifier == secret_package.identifier {
+                return Err(Error::IncorrectPackage);
+            }
+            if package.commitment.serialize().len() != secret_package.min_signers as usize - 1 {
+                return Err(Error::IncorrectNumberOfCommitments);
+            }
+            let value = evaluate_polynomial(identifier, zero, &secret_package.coefficients);
+            round2_packages.insert(*identifier, round2::Package::new(SigningShare::new(value)));
+        }
+
+        let refreshing_share = evaluate_polynomial(
+            &secret_package.identifier,
+            zero,
+            &secret_package.coefficients,
+        );
+        Ok((
+            round2::SecretPackage {
+                identifier: secret_package.identifier,
+                commitment: secret_package.commitment,
+                refreshing_share,
+                min_signers: secret_package.min_signers,
+                max_signers: secret_package.max_signers,
+            },
+            round2_packages,
+        ))
+    }
+
+    /// Performs the third and final part of the distributed refresh, given
+    /// the [`round1::Package`]s and [`round2::Package`]s received from the
+    /// other participants and the participant's current keys.
+    ///
+    /// It returns the refreshed [`KeyPackage`] and [`PublicKeyPackage`],
+    /// which replace the current ones. It fails with
+    /// [`Error::DuplicatedIdentifier`] if the packages contain the
+    /// participant's own identifier.
+    pub fn part3<C: Ciphersuite>(
+        round2_secret_package: &round2::SecretPackage<C>,
+        round1_packages: &HashMap<Identifier<C>, round1::Package<C>>,
+        round2_packages: &HashMap<Identifier<C>, round2::Package<C>>,
+        key_package: &KeyPackage<C>,
+        pubkeys: &PublicKeyPackage<C>,
+    ) -> Result<(KeyPackage<C>, PublicKeyPackage<C>), Error<C>> {
+        let secret_package = round2_secret_package;
+        if secret_package.identifier != *key_package.identifier() {
+            return Err(Error::UnknownIdentifier);
+        }
+        if secret_package.min_signers != *key_package.min_signers() {
+            return Err(Error::InvalidMinSigners);
+        }
+        if round1_packages.len() != secret_package.max_signers as usize - 1
+            || round2_packages.len() != round1_packages.len()
+            || pubkeys.signer_pubkeys().len() != secret_package.max_signers as usize
+        {
+            return Err(Error::IncorrectNumberOfPackages);
+        }
+        if !pubkeys
+            .signer_pubkeys()
+            .contains_key(&secret_package.identifier)
+        {
+            return Err(Error::UnknownIdentifier);
+        }
+        // A package keyed with the participant's own identifier would count
+        // its own commitment twice in place of another participant's.
+        if round1_packages.contains_key(&secret_package.identifier)
+            || round2_packages.contains_key(&secret_package.identifier)
+        {
+            return Err(Error::DuplicatedIdentifier);
+        }
+
+        let mut delta = secret_package.refreshing_share;
+        let mut commitments = vec![commitment_elements(&secret_package.commitment)];
+        for (identifier, round1_package) in round1_packages {
+            if !pubkeys.signer_pubkeys().contains_key(identifier) {
+                return Err(Error::UnknownIdentifier);
+            }
+            let round2_package = round2_packages
+                .get(identifier)
+                .ok_or(Error::PackageNotFound)?;
+            if round1_package.commitment.serialize().len()
+                != secret_package.min_signers as usize - 1
+            {
+                return Err(Error::IncorrectNumberOfCommitments);
+            }
+            verify_share(
+                &secret_package.identifier,
+                &round2_package.refreshing_share,
+                &round1_package.commitment,
+            )?;
+
+            delta = delta + round2_package.refreshing_share.to_scalar();
+            commitments.push(commitment_elements(&round1_package.commitment));
+        }
+
+        Ok((
+            refresh_key_package(key_package, delta),
+            refresh_pubkeys(pubkeys, &commitments),
+        ))
+    }
+}
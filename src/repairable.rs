@@ -0,0 +1,391 @@
+//! Repairable Threshold Scheme with typed and verifiable messages.
+//!
+//! The functions in `frost_core::frost::keys::repairable` exchange bare
+//! scalars, so a participant cannot tell which helper sent a wrong value.
+//! Here every helper also commits to the deltas it generates:
+//!
+//! - each helper calls [`repair_share_step_1`] and sends one [`Delta`] to
+//!   every helper, including itself;
+//! - each helper calls [`repair_share_step_2`], which checks the deltas it
+//!   received against their commitments, and sends the resulting [`Sigma`]
+//!   to the participant;
+//! - the participant calls [`repair_share_step_3`], which checks every
+//!   sigma against the commitments and the verifying shares of the helpers,
+//!   then the repaired [`SecretShare`] against its
+//!   [`VerifiableSecretSharingCommitment`] and its [`VerifyingShare`].
+//!
+//! A mismatch returns a [`RepairError`] naming the faulty helper. The
+//! commitments of a helper must be the same in all of its messages, so, as
+//! in the DKG, they must be sent over a broadcast channel or compared.
+//! Deltas and sigmas must be sent over confidential and authenticated
+//! channels.
+
+use std::collections::{BTreeSet, HashMap};
+
+use frost_core::{
+    frost::{
+        compute_lagrange_coefficient,
+        keys::{
+            repairable, KeyPackage, PublicKeyPackage, SecretShare, SigningShare,
+            VerifiableSecretSharingCommitment, VerifyingShare,
+        },
+        Identifier,
+    },
+    Ciphersuite, Error, Field, Group, Scalar,
+};
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+/// An error when repairing a share.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RepairError<C: Ciphersuite> {
+    /// The deltas of a helper do not match its commitments or its
+    /// verifying share.
+    InvalidDelta {
+        /// The helper that generated the deltas.
+        culprit: Identifier<C>,
+    },
+    /// The sigma of a helper does not match the commitments to the deltas
+    /// it received.
+    InvalidSigma {
+        /// The helper that sent the sigma.
+        culprit: Identifier<C>,
+    },
+    /// Any other error.
+    Frost(Error<C>),
+}
+
+impl<C: Ciphersuite> RepairError<C> {
+    /// Returns the identifier of the helper that caused the error, if any.
+    pub fn culprit(&self) -> Option<Identifier<C>> {
+        match self {
+            RepairError::InvalidDelta { culprit } | RepairError::InvalidSigma { culprit } => {
+                Some(*culprit)
+            }
+            RepairError::Frost(e) => e.culprit(),
+        }
+    }
+}
+
+impl<C: Ciphersuite> core::fmt::Display for RepairError<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RepairError::InvalidDelta { culprit } => {
+                write!(f, "Invalid delta from helper {culprit:?}.")
+            }
+            RepairError::InvalidSigma { culprit } => {
+                write!(f, "Invalid sigma from helper {culprit:?}.")
+            }
+            RepairError::Frost(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl<C: Ciphersuite> std::error::Error for RepairError<C> {}
+
+impl<C: Ciphersuite> From<Error<C>> for RepairError<C> {
+    fn from(e: Error<C>) -> Self {
+        RepairError::Frost(e)
+    }
+}
+
+/// A delta sent by a helper to another helper in step 1.
+///
+/// # Security
+///
+/// The delta must be sent over a confidential and authenticated channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "C: Ciphersuite + serde::Serialize",
+        deserialize = "C: Ciphersuite + serde::Deserialize<'de>"
+    ))
+)]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Delta<C: Ciphersuite> {
+    helper: Identifier<C>,
+    value: SigningShare<C>,
+    commitments: HashMap<Identifier<C>, VerifyingShare<C>>,
+}
+
+impl<C: Ciphersuite> Delta<C> {
+    /// Creates a new [`Delta`].
+    pub fn new(
+        helper: Identifier<C>,
+        value: SigningShare<C>,
+        commitments: HashMap<Identifier<C>, VerifyingShare<C>>,
+    ) -> Self {
+        Self {
+            helper,
+            value,
+            commitments,
+        }
+    }
+
+    /// The identifier of the helper that generated the delta.
+    pub fn helper(&self) -> &Identifier<C> {
+        &self.helper
+    }
+
+    /// The delta value.
+    pub fn value(&self) -> &SigningShare<C> {
+        &self.value
+    }
+
+    /// The commitments to all the deltas generated by the helper, by
+    /// recipient.
+    pub fn commitments(&self) -> &HashMap<Identifier<C>, VerifyingShare<C>> {
+        &self.commitments
+    }
+}
+
+/// A sigma sent by a helper to the participant in step 2.
+///
+/// # Security
+///
+/// The sigma must be sent over a confidential and authenticated channel.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "C: Ciphersuite + serde::Serialize",
+        deserialize = "C: Ciphersuite + serde::Deserialize<'de>"
+    ))
+)]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Sigma<C: Ciphersuite> {
+    helper: Identifier<C>,
+    value: SigningShare<C>,
+    commitments: HashMap<Identifier<C>, VerifyingShare<C>>,
+}
+
+impl<C: Ciphersuite> Sigma<C> {
+    /// Creates a new [`Sigma`].
+    pub fn new(
+        helper: Identifier<C>,
+        value: SigningShare<C>,
+        commitments: HashMap<Identifier<C>, VerifyingShare<C>>,
+    ) -> Self {
+        Self {
+            helper,
+            value,
+            commitments,
+        }
+    }
+
+    /// The identifier of the helper that sent the sigma.
+    pub fn helper(&self) -> &Identifier<C> {
+        &self.helper
+    }
+
+    /// The sigma value, the sum of the deltas received by the helper.
+    pub fn value(&self) -> &SigningShare<C> {
+        &self.value
+    }
+
+    /// The commitments to all the deltas generated by the helper in step 1,
+    /// by recipient.
+    pub fn commitments(&self) -> &HashMap<Identifier<C>, VerifyingShare<C>> {
+        &self.commitments
+    }
+}
+
+/// Checks that `identifiers` has no duplicates.
+fn identifier_set<C: Ciphersuite>(
+    identifiers: impl ExactSizeIterator<Item = Identifier<C>>,
+) -> Result<BTreeSet<Identifier<C>>, Error<C>> {
+    let len = identifiers.len();
+    let set: BTreeSet<_> = identifiers.collect();
+    if set.len() != len {
+        return Err(Error::DuplicatedIdentifier);
+    }
+    Ok(set)
+}
+
+/// Computes the deltas of step 1 of RTS from the helper holding
+/// `key_package` for `participant`, after checking that `participant` is not
+/// a helper and that there are at least `min_signers` helpers.
+///
+/// Shared by [`repair_share_step_1`] and
+/// [`enroll_step_1`](crate::enrollment::enroll_step_1).
+pub(crate) fn compute_deltas<C, R>(
+    helpers: &[Identifier<C>],
+    key_package: &KeyPackage<C>,
+    rng: &mut R,
+    participant: Identifier<C>,
+) -> Result<HashMap<Identifier<C>, Scalar<C>>, Error<C>>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    if helpers.contains(&participant) {
+        return Err(Error::DuplicatedIdentifier);
+    }
+    if helpers.len() < *key_package.min_signers() as usize {
+        return Err(Error::IncorrectNumberOfIdentifiers);
+    }
+
+    // Step 1 of RTS only uses the identifier and value of the share.
+    let commitment = VerifiableSecretSharingCommitment::deserialize(Vec::new())?;
+    let share = SecretShare::new(
+        *key_package.identifier(),
+        *key_package.secret_share(),
+        commitment,
+    );
+    repairable::repair_share_step_1(helpers, &share, rng, participant)
+}
+
+/// Step 1 of RTS, performed by each helper.
+///
+/// Generates the deltas from the helper holding `key_package` to repair the
+/// share of `participant`, where `helpers` contains the identifiers of all
+/// the helpers. Returns which [`Delta`] to send to which helper, including
+/// itself.
+pub fn repair_share_step_1<C, R>(
+    helpers: &[Identifier<C>],
+    key_package: &KeyPackage<C>,
+    rng: &mut R,
+    participant: Identifier<C>,
+) -> Result<HashMap<Identifier<C>, Delta<C>>, Error<C>>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    let deltas = compute_deltas(helpers, key_package, rng, participant)?;
+
+    let commitments: HashMap<_, _> = deltas
+        .iter()
+        .map(|(identifier, delta)| {
+            (
+                *identifier,
+                VerifyingShare::new(<C::Group>::generator() * *delta),
+            )
+        })
+        .collect();
+    Ok(deltas
+        .into_iter()
+        .map(|(identifier, delta)| {
+            (
+                identifier,
+                Delta::new(
+                    *key_package.identifier(),
+                    SigningShare::new(delta),
+                    commitments.clone(),
+                ),
+            )
+        })
+        .collect())
+}
+
+/// Step 2 of RTS, performed by each helper.
+///
+/// Checks the [`Delta`]s received from all the helpers, including its own,
+/// against their commitments, and sums them into the [`Sigma`] to send to
+/// the participant.
+pub fn repair_share_step_2<C: Ciphersuite>(
+    identifier: Identifier<C>,
+    deltas: &[Delta<C>],
+) -> Result<Sigma<C>, RepairError<C>> {
+    let helpers = identifier_set(deltas.iter().map(|delta| delta.helper))?;
+    // The helper's own delta carries the commitments forwarded in the sigma.
+    let commitments = deltas
+        .iter()
+        .find(|delta| delta.helper == identifier)
+        .ok_or(Error::UnknownIdentifier)?
+        .commitments
+        .clone();
+
+    let mut sigma = <<C::Group as Group>::Field>::zero();
+    for delta in deltas {
+        let culprit = delta.helper;
+        let keys: BTreeSet<_> = delta.commitments.keys().copied().collect();
+        if keys != helpers {
+            return Err(RepairError::InvalidDelta { culprit });
+        }
+        if VerifyingShare::from(delta.value) != delta.commitments[&identifier] {
+            return Err(RepairError::InvalidDelta { culprit });
+        }
+        sigma = sigma + delta.value.to_scalar();
+    }
+
+    Ok(Sigma::new(
+        identifier,
+        SigningShare::new(sigma),
+        commitments,
+    ))
+}
+
+/// Step 3 of RTS, performed by the participant.
+///
+/// Checks the [`Sigma`]s received from all the helpers against their
+/// commitments and the verifying shares in `pubkeys`, and sums them into the
+/// repaired [`SecretShare`] of `identifier`. The share is then checked
+/// against `commitment`, the participant's original
+/// [`VerifiableSecretSharingCommitment`], and its [`VerifyingShare`].
+pub fn repair_share_step_3<C: Ciphersuite>(
+    sigmas: &[Sigma<C>],
+    identifier: Identifier<C>,
+    commitment: &VerifiableSecretSharingCommitment<C>,
+    pubkeys: &PublicKeyPackage<C>,
+) -> Result<SecretShare<C>, RepairError<C>> {
+    let helpers = identifier_set(sigmas.iter().map(|sigma| sigma.helper))?;
+    if helpers.contains(&identifier) {
+        return Err(Error::DuplicatedIdentifier.into());
+    }
+    if helpers.len() < commitment.serialize().len() {
+        return Err(Error::IncorrectNumberOfIdentifiers.into());
+    }
+    let verifying_share = pubkeys
+        .signer_pubkeys()
+        .get(&identifier)
+        .ok_or(Error::UnknownIdentifier)?;
+
+    // The deltas of each helper must add up to its share of the
+    // participant's verifying share.
+    for sigma in sigmas {
+        let culprit = sigma.helper;
+        let keys: BTreeSet<_> = sigma.commitments.keys().copied().collect();
+        if keys != helpers {
+            return Err(RepairError::InvalidDelta { culprit });
+        }
+        let helper_share = pubkeys
+            .signer_pubkeys()
+            .get(&culprit)
+            .ok_or(Error::UnknownIdentifier)?;
+        let zeta = compute_lagrange_coefficient(&helpers, Some(identifier), culprit)?;
+        let sum = sigma
+            .commitments
+            .values()
+            .fold(<C::Group>::identity(), |sum, c| sum + c.to_element());
+        if sum != helper_share.to_element() * zeta {
+            return Err(RepairError::InvalidDelta { culprit });
+        }
+    }
+
+    // Each sigma must be the sum of the deltas sent to its helper.
+    let mut share = <<C::Group as Group>::Field>::zero();
+    for sigma in sigmas {
+        let culprit = sigma.helper;
+        let expected = sigmas.iter().fold(<C::Group>::identity(), |sum, s| {
+            sum + s.commitments[&culprit].to_element()
+        });
+        if VerifyingShare::from(sigma.value).to_element() != expected {
+            return Err(RepairError::InvalidSigma { culprit });
+        }
+        share = share + sigma.value.to_scalar();
+    }
+
+    let secret_share = SecretShare::new(identifier, SigningShare::new(share), commitment.clone());
+    let (repaired_verifying_share, group_public) = secret_share.verify()?;
+    if repaired_verifying_share != *verifying_share || group_public != *pubkeys.group_public() {
+        return Err(Error::InvalidSecretShare.into());
+    }
+    Ok(secret_share)
+}
@@ -0,0 +1,177 @@
+//! Resharing of the group secret to a new set of participants and threshold.
+//!
+//! A set of at least `min_signers` current participants, the dealers, hands
+//! the group secret to new participants with new `min_signers` and
+//! `max_signers`, without reconstructing it:
+//!
+//! - each dealer calls [`part1`], which shares its Lagrange-weighted signing
+//!   share with a random polynomial of degree `new_min_signers - 1`, and
+//!   sends each resulting [`SecretShare`] to its new participant over a
+//!   confidential and authenticated channel;
+//! - each new participant calls [`part2`] with the shares received from all
+//!   the dealers, which checks them against their commitments and the
+//!   current [`PublicKeyPackage`], and returns its [`KeyPackage`] and the new
+//!   [`PublicKeyPackage`] with the same group verifying key.
+//!
+//! The constant term of each dealer's commitment must match its weighted
+//! verifying share, so a dealer cannot change the group secret. As in the
+//! DKG, the new participants must make sure they received the same
+//! commitment from each dealer, e.g. by comparing them.
+//!
+//! The current participants must discard their key packages afterwards:
+//! the new shares do not combine with the old ones.
+
+use std::collections::{BTreeSet, HashMap};
+
+use frost_core::{
+    frost::{
+        compute_lagrange_coefficient,
+        keys::{KeyPackage, PublicKeyPackage, SecretShare, SigningShare, VerifyingShare},
+        Identifier,
+    },
+    Ciphersuite, Error, Field, Group, VerifyingKey,
+};
+use rand_core::{CryptoRng, RngCore};
+
+use crate::polynomial::{
+    commit, commitment_elements, evaluate_commitment, evaluate_polynomial, random_coefficients,
+    validate_num_of_signers,
+};
+
+/// Checks that `identifiers` has no duplicates and enough participants for
+/// `min_signers`.
+fn validate_identifiers<C: Ciphersuite>(
+    identifiers: &[Identifier<C>],
+    min_signers: u16,
+) -> Result<BTreeSet<Identifier<C>>, Error<C>> {
+    let max_signers = u16::try_from(identifiers.len()).map_err(|_| Error::InvalidMaxSigners)?;
+    validate_num_of_signers::<C>(min_signers, max_signers)?;
+
+    let set: BTreeSet<_> = identifiers.iter().copied().collect();
+    if set.len() != identifiers.len() {
+        return Err(Error::DuplicatedIdentifier);
+    }
+    Ok(set)
+}
+
+/// Performed by each dealer: shares its signing share, weighted by its
+/// Lagrange coefficient in `dealers`, among `new_identifiers` with the
+/// threshold `new_min_signers`.
+///
+/// `dealers` are the identifiers of the current participants taking part,
+/// at least the current `min_signers` of them. Returns the [`SecretShare`]
+/// to send to each new participant.
+pub fn part1<C, R>(
+    key_package: &KeyPackage<C>,
+    dealers: &BTreeSet<Identifier<C>>,
+    new_identifiers: &[Identifier<C>],
+    new_min_signers: u16,
+    rng: &mut R,
+) -> Result<HashMap<Identifier<C>, SecretShare<C>>, Error<C>>
+where
+    C: Ciphersuite,
+    R: RngCore + CryptoRng,
+{
+    validate_identifiers(new_identifiers, new_min_signers)?;
+    if !dealers.contains(key_package.identifier()) {
+        return Err(Error::UnknownIdentifier);
+    }
+    if dealers.len() < *key_package.min_signers() as usize {
+        return Err(Error::IncorrectNumberOfShares);
+    }
+
+    let lambda = compute_lagrange_coefficient(dealers, None, *key_package.identifier())?;
+    let constant = lambda * key_package.secret_share().to_scalar();
+    let coefficients = random_coefficients::<C, R>(new_min_signers as usize - 1, rng);
+    let commitment = commit(
+        &core::iter::once(constant)
+            .chain(coefficients.iter().copied())
+            .collect::<Vec<_>>(),
+    )?;
+
+    Ok(new_identifiers
+        .iter()
+        .map(|identifier| {
+            let value = evaluate_polynomial(identifier, constant, &coefficients);
+            (
+                *identifier,
+                SecretShare::new(*identifier, SigningShare::new(value), commitment.clone()),
+            )
+        })
+        .collect())
+}
+
+/// Performed by each new participant: checks the [`SecretShare`]s received
+/// from all the dealers against `pubkeys`, the current
+/// [`PublicKeyPackage`], and combines them.
+///
+/// `new_identifiers` and `new_min_signers` must be the values given to
+/// [`part1`]. Returns the participant's [`KeyPackage`] and the new
+/// [`PublicKeyPackage`], whose group verifying key is unchanged.
+pub fn part2<C: Ciphersuite>(
+    identifier: Identifier<C>,
+    new_identifiers: &[Identifier<C>],
+    new_min_signers: u16,
+    secret_shares: &HashMap<Identifier<C>, SecretShare<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+) -> Result<(KeyPackage<C>, PublicKeyPackage<C>), Error<C>> {
+    validate_identifiers(new_identifiers, new_min_signers)?;
+    if !new_identifiers.contains(&identifier) {
+        return Err(Error::UnknownIdentifier);
+    }
+
+    let dealers: BTreeSet<_> = secret_shares.keys().copied().collect();
+    let mut signing_share = <<C::Group as Group>::Field>::zero();
+    let mut group_public = <C::Group>::identity();
+    let mut commitments = Vec::with_capacity(secret_shares.len());
+    for (dealer, secret_share) in secret_shares {
+        if *secret_share.identifier() != identifier {
+            return Err(Error::UnknownIdentifier);
+        }
+        let verifying_share = pubkeys
+            .signer_pubkeys()
+            .get(dealer)
+            .ok_or(Error::UnknownIdentifier)?;
+
+        let elements = commitment_elements(secret_share.commitment());
+        if elements.len() != new_min_signers as usize {
+            return Err(Error::IncorrectNumberOfCommitments);
+        }
+        secret_share.verify()?;
+
+        let lambda = compute_lagrange_coefficient(&dealers, None, *dealer)?;
+        if elements[0] != verifying_share.to_element() * lambda {
+            return Err(Error::IncorrectCommitment);
+        }
+
+        signing_share = signing_share + secret_share.value().to_scalar();
+        group_public = group_public + elements[0];
+        commitments.push(elements);
+    }
+    // Fails unless at least `min_signers` current participants dealt.
+    if group_public != pubkeys.group_public().to_element() {
+        return Err(Error::IncorrectCommitment);
+    }
+
+    let signer_pubkeys = new_identifiers
+        .iter()
+        .map(|identifier| {
+            let element = commitments.iter().fold(<C::Group>::identity(), |sum, c| {
+                sum + evaluate_commitment(identifier, c[0], &c[1..])
+            });
+            (*identifier, VerifyingShare::new(element))
+        })
+        .collect();
+
+    let signing_share = SigningShare::new(signing_share);
+    Ok((
+        KeyPackage::new(
+            identifier,
+            signing_share,
+            VerifyingShare::from(signing_share),
+            VerifyingKey::new(group_public),
+            new_min_signers,
+        ),
+        PublicKeyPackage::new(signer_pubkeys, VerifyingKey::new(group_public)),
+    ))
+}
@@ -0,0 +1,54 @@
+//! Serde adapters for the BabyJubJub elements and scalars held by the
+//! threshold primitives, as the hex strings of their default serialization.
+
+use frost_core::serde::{de::Error as _, Deserialize, Deserializer, Serializer};
+
+use crate::babyjubjub::EdwardsProjective;
+use crate::{BabyJubJubGroup, BabyJubJubScalarField, Field, Fr, Group};
+
+/// Decodes a hex string of exactly 32 bytes.
+fn decode<'de, D: Deserializer<'de>>(deserializer: D) -> Result<[u8; 32], D::Error> {
+    let string = String::deserialize(deserializer)?;
+    let bytes = ::hex::decode(string).map_err(D::Error::custom)?;
+    let len = bytes.len();
+    bytes
+        .try_into()
+        .map_err(|_| D::Error::invalid_length(len, &"32 bytes"))
+}
+
+/// Group elements, which must be in the prime-order subgroup and not the
+/// identity.
+pub(crate) mod element {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(
+        element: &EdwardsProjective,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&::hex::encode(BabyJubJubGroup::serialize(element)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<EdwardsProjective, D::Error> {
+        BabyJubJubGroup::deserialize(&decode(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+/// Scalars, which must be canonical.
+pub(crate) mod scalar {
+    use super::*;
+
+    pub(crate) fn serialize<S: Serializer>(scalar: &Fr, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&::hex::encode(BabyJubJubScalarField::serialize(scalar)))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Fr, D::Error> {
+        let bytes = decode(deserializer)?;
+        let scalar = BabyJubJubScalarField::deserialize(&bytes).map_err(D::Error::custom)?;
+        if BabyJubJubScalarField::serialize(&scalar) != bytes {
+            return Err(D::Error::custom("non-canonical scalar"));
+        }
+        Ok(scalar)
+    }
+}
@@ -0,0 +1,322 @@
+//! Solidity verifier generation and ABI encoding.
+//!
+//! [`verifier_library`] emits a self-contained Solidity library that verifies
+//! signatures of a [`SolidityCiphersuite`] on the EVM: it decompresses the
+//! points, recomputes the challenge and checks the cofactored equation
+//! `8 * (z * B - c * A - R) == 0` like frost-core, using projective twisted
+//! Edwards arithmetic and the `modexp` precompile for inversions and square
+//! roots.
+//!
+//! The library exposes
+//!
+//! ```solidity
+//! function verify(bytes32 A, bytes32 R, uint256 z, bytes memory message)
+//!     public view returns (bool)
+//! ```
+//!
+//! where `A` and `R` are the 32-byte serializations of the verifying key and
+//! signature commitment, passed unchanged, and `z` is the signature response
+//! as an integer. [`abi_encode_verifying_key`], [`abi_encode_signature`] and
+//! [`abi_encode_verify_call`] encode these arguments from the FROST types.
+//!
+//! Compared to frost-core, the library additionally rejects non-canonical
+//! responses (`z >= r`), but it does not check that `A` and `R` are in the
+//! prime-order subgroup; the cofactored equation makes this unnecessary for
+//! signature validity.
+
+use ark_ff::{BigInteger, FftField, Field as ArkField, PrimeField};
+use frost_core::{Ciphersuite, Signature, VerifyingKey};
+use sha3::{Digest, Keccak256};
+
+use crate::babyjubjub::{EdwardsConfig, GENERATOR_X, GENERATOR_Y};
+use crate::{BabyJubJubGroup, BabyJubJubHash, BabyJubJubKeccak256, Fq, Fr};
+
+/// Ciphersuites whose challenge can be computed by a Solidity verifier.
+pub trait SolidityCiphersuite: Ciphersuite<Group = BabyJubJubGroup> {
+    /// Returns the body of the Solidity function
+    /// `challenge(bytes32 R, bytes32 A, bytes memory message) internal pure
+    /// returns (uint256)`, which computes the challenge scalar modulo the
+    /// group order `R_ORDER`.
+    fn challenge_body() -> String;
+}
+
+impl SolidityCiphersuite for BabyJubJubKeccak256 {
+    /// See [`BabyJubJubKeccak256`] for the construction.
+    fn challenge_body() -> String {
+        let r256 = Fr::from(2u64).pow([256]);
+        CHALLENGE_KECCAK256
+            .replace(
+                "{DST}",
+                &(<Self as BabyJubJubHash>::CONTEXT_STRING.to_owned() + "chal"),
+            )
+            .replace("{R256}", &decimal(r256.into_bigint()))
+    }
+}
+
+const CHALLENGE_KECCAK256: &str = r#"        bytes memory preimage = abi.encodePacked("{DST}", R, A, message);
+        uint256 h0 = uint256(keccak256(abi.encodePacked(preimage, uint8(0))));
+        uint256 h1 = uint256(keccak256(abi.encodePacked(preimage, uint8(1))));
+        // 2^256 mod R_ORDER
+        uint256 r256 = {R256};
+        return addmod(mulmod(h0, r256, R_ORDER), h1, R_ORDER);"#;
+
+fn decimal(value: impl BigInteger) -> String {
+    value.to_string()
+}
+
+/// Returns the Solidity source of a library named `name` verifying
+/// signatures of the ciphersuite `C`.
+pub fn verifier_library<C: SolidityCiphersuite>(name: &str) -> String {
+    use ark_ec::twisted_edwards::TECurveConfig;
+
+    let q = {
+        let mut q = Fq::MODULUS;
+        q.divn(Fq::TWO_ADICITY);
+        q
+    };
+    let mut half = Fq::MODULUS;
+    half.div2();
+
+    LIBRARY
+        .replace("{NAME}", name)
+        .replace("{ID}", C::ID)
+        .replace("{CHALLENGE}", &C::challenge_body())
+        .replace("{P}", &decimal(Fq::MODULUS))
+        .replace("{HALF_P}", &decimal(half))
+        .replace("{R_ORDER}", &decimal(Fr::MODULUS))
+        .replace("{A}", &decimal(EdwardsConfig::COEFF_A.into_bigint()))
+        .replace("{D}", &decimal(EdwardsConfig::COEFF_D.into_bigint()))
+        .replace("{GX}", &decimal(GENERATOR_X.into_bigint()))
+        .replace("{GY}", &decimal(GENERATOR_Y.into_bigint()))
+        .replace("{S}", &Fq::TWO_ADICITY.to_string())
+        .replace("{Q}", &decimal(q))
+        .replace("{ROOT}", &decimal(Fq::TWO_ADIC_ROOT_OF_UNITY.into_bigint()))
+}
+
+const LIBRARY: &str = r#"// SPDX-License-Identifier: MIT
+// Generated by frost-bjj. Verifies {ID} signatures.
+pragma solidity ^0.8.19;
+
+library {NAME} {
+    // Base field modulus
+    uint256 internal constant P = {P};
+    // (P - 1) / 2
+    uint256 internal constant HALF_P = {HALF_P};
+    // Prime-order subgroup order
+    uint256 internal constant R_ORDER = {R_ORDER};
+    // Curve coefficients of a * x^2 + y^2 = 1 + d * x^2 * y^2
+    uint256 internal constant COEFF_A = {A};
+    uint256 internal constant COEFF_D = {D};
+    // Generator
+    uint256 internal constant GX = {GX};
+    uint256 internal constant GY = {GY};
+    // P - 1 = Q * 2^S with Q odd, and a primitive 2^S-th root of unity
+    uint256 internal constant S = {S};
+    uint256 internal constant Q = {Q};
+    uint256 internal constant ROOT = {ROOT};
+
+    struct Point {
+        uint256 x;
+        uint256 y;
+        uint256 z;
+    }
+
+    /// Verifies the signature (R, z) of `message` under the verifying key A,
+    /// where A and R are the 32-byte FROST serializations of the points.
+    function verify(bytes32 A, bytes32 R, uint256 z, bytes memory message)
+        public
+        view
+        returns (bool)
+    {
+        if (z >= R_ORDER) {
+            return false;
+        }
+        (bool okA, Point memory pA) = decompress(A);
+        (bool okR, Point memory pR) = decompress(R);
+        if (!okA || !okR) {
+            return false;
+        }
+        uint256 c = challenge(R, A, message);
+
+        Point memory zB = mul(Point(GX, GY, 1), z);
+        Point memory cA = mul(pA, c);
+        Point memory check = add(zB, neg(add(cA, pR)));
+        for (uint256 i = 0; i < 3; i++) {
+            check = add(check, check);
+        }
+        return check.x == 0 && check.y == check.z;
+    }
+
+    function challenge(bytes32 R, bytes32 A, bytes memory message)
+        internal
+        pure
+        returns (uint256)
+    {
+{CHALLENGE}
+    }
+
+    /// Decodes a point from its little-endian `y` coordinate, whose most
+    /// significant bit is set when `x > (P - 1) / 2`.
+    function decompress(bytes32 encoded) internal view returns (bool, Point memory) {
+        uint256 value = reverseBytes(uint256(encoded));
+        bool sign = (value >> 255) == 1;
+        uint256 y = value & ((1 << 255) - 1);
+        if (y >= P) {
+            return (false, Point(0, 1, 1));
+        }
+
+        // x^2 = (1 - y^2) / (a - d * y^2)
+        uint256 y2 = mulmod(y, y, P);
+        uint256 num = addmod(1, P - y2, P);
+        uint256 den = addmod(COEFF_A, P - mulmod(COEFF_D, y2, P), P);
+        uint256 x2 = mulmod(num, expmod(den, P - 2), P);
+        (bool ok, uint256 x) = sqrt(x2);
+        if (!ok || (x == 0 && sign)) {
+            return (false, Point(0, 1, 1));
+        }
+        if ((x > HALF_P) != sign) {
+            x = P - x;
+        }
+        return (true, Point(x, y, 1));
+    }
+
+    /// Tonelli-Shanks square root.
+    function sqrt(uint256 n) internal view returns (bool, uint256) {
+        if (n == 0) {
+            return (true, 0);
+        }
+        uint256 m = S;
+        uint256 c = ROOT;
+        uint256 t = expmod(n, Q);
+        uint256 r = expmod(n, (Q + 1) / 2);
+        while (t != 1) {
+            uint256 i = 0;
+            uint256 t2 = t;
+            while (t2 != 1) {
+                t2 = mulmod(t2, t2, P);
+                i++;
+                if (i == m) {
+                    return (false, 0);
+                }
+            }
+            uint256 b = c;
+            for (uint256 j = 0; j < m - i - 1; j++) {
+                b = mulmod(b, b, P);
+            }
+            m = i;
+            c = mulmod(b, b, P);
+            t = mulmod(t, c, P);
+            r = mulmod(r, b, P);
+        }
+        return (true, r);
+    }
+
+    /// Unified projective addition (add-2008-bbjlp), complete on this curve.
+    function add(Point memory p1, Point memory p2) internal pure returns (Point memory) {
+        uint256 a = mulmod(p1.z, p2.z, P);
+        uint256 b = mulmod(a, a, P);
+        uint256 c = mulmod(p1.x, p2.x, P);
+        uint256 d = mulmod(p1.y, p2.y, P);
+        uint256 e = mulmod(mulmod(COEFF_D, c, P), d, P);
+        uint256 f = addmod(b, P - e, P);
+        uint256 g = addmod(b, e, P);
+        uint256 h = mulmod(addmod(p1.x, p1.y, P), addmod(p2.x, p2.y, P), P);
+        uint256 x3 = mulmod(mulmod(a, f, P), addmod(h, P - addmod(c, d, P), P), P);
+        uint256 y3 = mulmod(mulmod(a, g, P), addmod(d, P - mulmod(COEFF_A, c, P), P), P);
+        return Point(x3, y3, mulmod(f, g, P));
+    }
+
+    function neg(Point memory p) internal pure returns (Point memory) {
+        return Point((P - p.x) % P, p.y, p.z);
+    }
+
+    /// Double-and-add scalar multiplication.
+    function mul(Point memory p, uint256 k) internal pure returns (Point memory) {
+        Point memory result = Point(0, 1, 1);
+        for (uint256 i = 256; i > 0; i--) {
+            result = add(result, result);
+            if (((k >> (i - 1)) & 1) == 1) {
+                result = add(result, p);
+            }
+        }
+        return result;
+    }
+
+    function expmod(uint256 base, uint256 e) internal view returns (uint256 result) {
+        bool success;
+        assembly {
+            let ptr := mload(0x40)
+            mstore(ptr, 0x20)
+            mstore(add(ptr, 0x20), 0x20)
+            mstore(add(ptr, 0x40), 0x20)
+            mstore(add(ptr, 0x60), base)
+            mstore(add(ptr, 0x80), e)
+            mstore(add(ptr, 0xa0), P)
+            success := staticcall(gas(), 0x05, ptr, 0xc0, ptr, 0x20)
+            result := mload(ptr)
+        }
+        require(success, "modexp failed");
+    }
+
+    function reverseBytes(uint256 v) internal pure returns (uint256) {
+        v = ((v & 0xFF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00) >> 8)
+            | ((v & 0x00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF00FF) << 8);
+        v = ((v & 0xFFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000) >> 16)
+            | ((v & 0x0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF0000FFFF) << 16);
+        v = ((v & 0xFFFFFFFF00000000FFFFFFFF00000000FFFFFFFF00000000FFFFFFFF00000000) >> 32)
+            | ((v & 0x00000000FFFFFFFF00000000FFFFFFFF00000000FFFFFFFF00000000FFFFFFFF) << 32);
+        v = ((v & 0xFFFFFFFFFFFFFFFF0000000000000000FFFFFFFFFFFFFFFF0000000000000000) >> 64)
+            | ((v & 0x0000000000000000FFFFFFFFFFFFFFFF0000000000000000FFFFFFFFFFFFFFFF) << 64);
+        return (v >> 128) | (v << 128);
+    }
+}
+"#;
+
+/// ABI-encodes a verifying key as the `bytes32` argument `A`.
+pub fn abi_encode_verifying_key<C>(verifying_key: &VerifyingKey<C>) -> [u8; 32]
+where
+    C: Ciphersuite<Group = BabyJubJubGroup>,
+{
+    verifying_key.serialize()
+}
+
+/// ABI-encodes a signature as the `(bytes32 R, uint256 z)` arguments.
+pub fn abi_encode_signature<C>(signature: &Signature<C>) -> [u8; 64]
+where
+    C: Ciphersuite<Group = BabyJubJubGroup, SignatureSerialization = [u8; 64]>,
+{
+    let mut encoded = signature.serialize();
+    // z is serialized little-endian; ABI integers are big-endian.
+    encoded[32..].reverse();
+    encoded
+}
+
+/// ABI-encodes the calldata of `verify(A, R, z, message)`.
+pub fn abi_encode_verify_call<C>(
+    verifying_key: &VerifyingKey<C>,
+    msg: &[u8],
+    signature: &Signature<C>,
+) -> Vec<u8>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup, SignatureSerialization = [u8; 64]>,
+{
+    let selector = Keccak256::digest(b"verify(bytes32,bytes32,uint256,bytes)");
+
+    let mut calldata = Vec::with_capacity(4 + 5 * 32 + msg.len() + 31);
+    calldata.extend_from_slice(&selector[..4]);
+    calldata.extend_from_slice(&abi_encode_verifying_key(verifying_key));
+    calldata.extend_from_slice(&abi_encode_signature(signature));
+    // Offset of the dynamic `message` argument, after the four head words.
+    calldata.extend_from_slice(&word(4 * 32));
+    calldata.extend_from_slice(&word(msg.len()));
+    calldata.extend_from_slice(msg);
+    calldata.resize(calldata.len() + (32 - msg.len() % 32) % 32, 0);
+    calldata
+}
+
+/// Encodes `value` as a big-endian 32-byte ABI word.
+fn word(value: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
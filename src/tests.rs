@@ -1,8 +1,32 @@
-
 mod batch;
 mod coefficient_commitment;
 mod deserialize;
 mod proptests;
+mod repairable;
 mod vss_commitment;
 
+mod cheaters;
+mod circomlib;
+#[cfg(feature = "serde")]
+mod decimal;
+mod dkg;
 mod ec_ops;
+mod ecdh;
+mod ecies;
+mod elgamal;
+mod enrollment;
+#[cfg(feature = "groth16")]
+mod groth16;
+mod helpers;
+mod jubjub;
+mod keccak256;
+mod pack;
+mod poseidon;
+#[cfg(feature = "r1cs")]
+mod r1cs;
+mod refresh;
+mod resharing;
+#[cfg(feature = "solc")]
+mod solc;
+mod solidity;
+mod vrf;
@@ -0,0 +1,96 @@
+use std::collections::{BTreeMap, HashMap};
+
+use frost_core::frost::{self, keys::KeyPackage, round2::SignatureShare, SigningPackage};
+use rand::thread_rng;
+
+use super::helpers::generate;
+use crate::cheaters::{self, AggregateError, ShareFault};
+use crate::*;
+
+type C = BabyJubJubSha256;
+
+/// Runs both signing rounds with `key_packages` and returns the signing
+/// package and the signature shares.
+fn sign_shares(
+    key_packages: &[&KeyPackage<C>],
+    msg: &[u8],
+) -> (
+    SigningPackage<C>,
+    HashMap<frost::Identifier<C>, SignatureShare<C>>,
+) {
+    let mut rng = thread_rng();
+    let mut nonces = HashMap::new();
+    let mut commitments = BTreeMap::new();
+    for key_package in key_packages {
+        let (nonce, commitment) = frost::round1::commit(key_package.secret_share(), &mut rng);
+        nonces.insert(*key_package.identifier(), nonce);
+        commitments.insert(*key_package.identifier(), commitment);
+    }
+    let signing_package = SigningPackage::new(commitments, msg);
+    let signature_shares = key_packages
+        .iter()
+        .map(|key_package| {
+            let identifier = *key_package.identifier();
+            let share =
+                frost::round2::sign(&signing_package, &nonces[&identifier], key_package).unwrap();
+            (identifier, share)
+        })
+        .collect();
+    (signing_package, signature_shares)
+}
+
+#[test]
+fn check_aggregate_identifies_all_cheaters() {
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let signers: Vec<_> = key_packages.values().take(4).collect();
+    let ids: Vec<_> = key_packages.keys().copied().collect();
+    let msg = b"message to sign";
+    let (signing_package, signature_shares) = sign_shares(&signers, msg);
+
+    let signature = cheaters::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+    assert!(
+        cheaters::identify_cheaters(&signing_package, &signature_shares, &pubkeys)
+            .unwrap()
+            .is_empty()
+    );
+
+    // Two signers send the shares of others, so both are invalid.
+    let mut shares = signature_shares.clone();
+    shares.insert(ids[0], signature_shares[&ids[2]]);
+    shares.insert(ids[1], signature_shares[&ids[0]]);
+    let err = cheaters::aggregate(&signing_package, &shares, &pubkeys).unwrap_err();
+    assert_eq!(
+        err,
+        AggregateError::Cheaters(BTreeMap::from([
+            (ids[0], ShareFault::InvalidShare),
+            (ids[1], ShareFault::InvalidShare),
+        ]))
+    );
+    assert_eq!(err.culprits(), vec![ids[0], ids[1]]);
+
+    // Missing and unexpected shares are reported along with invalid ones.
+    shares.remove(&ids[2]);
+    shares.insert(ids[4], signature_shares[&ids[3]]);
+    assert_eq!(
+        cheaters::aggregate(&signing_package, &shares, &pubkeys),
+        Err(AggregateError::Cheaters(BTreeMap::from([
+            (ids[0], ShareFault::InvalidShare),
+            (ids[1], ShareFault::InvalidShare),
+            (ids[2], ShareFault::MissingShare),
+            (ids[4], ShareFault::MissingCommitment),
+        ])))
+    );
+
+    // Signers unknown to the public key package are reported too.
+    let mut signer_pubkeys = pubkeys.signer_pubkeys().clone();
+    signer_pubkeys.remove(&ids[3]);
+    let others = frost::keys::PublicKeyPackage::new(signer_pubkeys, *pubkeys.group_public());
+    assert_eq!(
+        cheaters::aggregate(&signing_package, &signature_shares, &others),
+        Err(AggregateError::Cheaters(BTreeMap::from([(
+            ids[3],
+            ShareFault::UnknownSigner
+        )])))
+    );
+}
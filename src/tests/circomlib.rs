@@ -0,0 +1,261 @@
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+use ark_ec::{AffineRepr, Group as _};
+use ark_ed_on_bn254::Fq;
+use ark_ff::PrimeField;
+use lazy_static::lazy_static;
+use rand::thread_rng;
+use serde_json::Value;
+
+use crate::babyjubjub::{EdwardsAffine, EdwardsProjective};
+use crate::circomlib::{
+    self as frost, field_to_message, prover_toml, verify_circomlib, witness_input, WitnessInput,
+};
+use crate::poseidon::poseidon;
+use crate::*;
+
+type C = BabyJubJubCircomlib;
+
+lazy_static! {
+    pub static ref CIRCOMLIB_EDDSA: Value =
+        serde_json::from_str(include_str!("../../tests/helpers/circomlib-eddsa.json").trim())
+            .unwrap();
+}
+
+fn field(value: &Value) -> Fq {
+    Fq::from_str(value.as_str().unwrap()).unwrap()
+}
+
+fn point(value: &Value) -> EdwardsProjective {
+    EdwardsAffine::new(field(&value[0]), field(&value[1])).into()
+}
+
+#[test]
+fn check_circomlibjs_vectors() {
+    for vector in CIRCOMLIB_EDDSA["vectors"].as_array().unwrap() {
+        let msg = field(&vector["message"]);
+        let S = Fr::from_str(vector["S"].as_str().unwrap()).unwrap();
+
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&BabyJubJubGroup::serialize(&point(&vector["R8"])));
+        bytes[32..].copy_from_slice(&BabyJubJubScalarField::serialize(&S));
+        let signature = frost_core::Signature::<C>::deserialize(bytes).unwrap();
+        let verifying_key = frost_core::VerifyingKey::<C>::new(point(&vector["A"]));
+
+        assert!(verify_circomlib(msg, &signature, &verifying_key).is_ok());
+        assert!(verifying_key
+            .verify(&field_to_message(msg), &signature)
+            .is_ok());
+        assert_eq!(
+            verify_circomlib(msg + Fq::from(1u64), &signature, &verifying_key),
+            Err(frost_core::Error::InvalidSignature)
+        );
+    }
+}
+
+#[test]
+fn check_witness_input_vectors() {
+    for vector in CIRCOMLIB_EDDSA["vectors"].as_array().unwrap() {
+        let str = |value: &Value| value.as_str().unwrap().to_owned();
+        let msg = field_to_message(field(&vector["message"]));
+
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&BabyJubJubGroup::serialize(&point(&vector["R8"])));
+        bytes[32..].copy_from_slice(&BabyJubJubScalarField::serialize(
+            &Fr::from_str(vector["S"].as_str().unwrap()).unwrap(),
+        ));
+        let signature = frost_core::Signature::<C>::deserialize(bytes).unwrap();
+        let verifying_key = frost_core::VerifyingKey::<C>::new(point(&vector["A"]));
+
+        let input = witness_input(&msg, &signature, &verifying_key).unwrap();
+        assert_eq!(
+            input,
+            WitnessInput {
+                enabled: "1".to_owned(),
+                Ax: str(&vector["A"][0]),
+                Ay: str(&vector["A"][1]),
+                S: str(&vector["S"]),
+                R8x: str(&vector["R8"][0]),
+                R8y: str(&vector["R8"][1]),
+                M: str(&vector["message"]),
+            }
+        );
+        #[cfg(feature = "serde")]
+        assert_eq!(
+            serde_json::to_value(&input).unwrap(),
+            serde_json::json!({
+                "enabled": "1",
+                "Ax": vector["A"][0],
+                "Ay": vector["A"][1],
+                "S": vector["S"],
+                "R8x": vector["R8"][0],
+                "R8y": vector["R8"][1],
+                "M": vector["message"],
+            })
+        );
+
+        assert_eq!(
+            witness_input(b"other message", &signature, &verifying_key),
+            Err(frost_core::Error::InvalidSignature)
+        );
+    }
+}
+
+/// Noir's `std::eddsa::eddsa_poseidon_verify`.
+fn noir_eddsa_poseidon_verify(
+    pub_key_x: Fq,
+    pub_key_y: Fq,
+    signature_s: Fq,
+    signature_r8_x: Fq,
+    signature_r8_y: Fq,
+    message: Fq,
+) -> bool {
+    let pub_key = EdwardsAffine::new_unchecked(pub_key_x, pub_key_y);
+    let signature_r8 = EdwardsAffine::new_unchecked(signature_r8_x, signature_r8_y);
+    if !pub_key.is_on_curve() || !signature_r8.is_on_curve() {
+        return false;
+    }
+    if signature_s.into_bigint() >= Fr::MODULUS {
+        return false;
+    }
+
+    let hash = poseidon(&[
+        signature_r8_x,
+        signature_r8_y,
+        pub_key_x,
+        pub_key_y,
+        message,
+    ]);
+    let pub_key_mul_8 = pub_key.into_group().double().double().double();
+    let right2 = pub_key_mul_8.mul_bigint(hash.into_bigint());
+    let left = BabyJubJubGroup::generator().mul_bigint(signature_s.into_bigint());
+    left == signature_r8 + right2
+}
+
+/// Parses the entries written by [`prover_toml`].
+fn parse_prover_toml(toml: &str) -> HashMap<&str, Fq> {
+    toml.lines()
+        .map(|line| {
+            let (name, value) = line.split_once(" = ").unwrap();
+            let hex = value
+                .strip_prefix("\"0x")
+                .unwrap()
+                .strip_suffix('"')
+                .unwrap();
+            (
+                name,
+                Fq::from_be_bytes_mod_order(&hex::decode(hex).unwrap()),
+            )
+        })
+        .collect()
+}
+
+fn noir_verify(toml: &str) -> bool {
+    let inputs = parse_prover_toml(toml);
+    noir_eddsa_poseidon_verify(
+        inputs["pub_key_x"],
+        inputs["pub_key_y"],
+        inputs["signature_s"],
+        inputs["signature_r8_x"],
+        inputs["signature_r8_y"],
+        inputs["message"],
+    )
+}
+
+#[test]
+fn check_prover_toml_vectors() {
+    for vector in CIRCOMLIB_EDDSA["vectors"].as_array().unwrap() {
+        let msg = field_to_message(field(&vector["message"]));
+
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&BabyJubJubGroup::serialize(&point(&vector["R8"])));
+        bytes[32..].copy_from_slice(&BabyJubJubScalarField::serialize(
+            &Fr::from_str(vector["S"].as_str().unwrap()).unwrap(),
+        ));
+        let signature = frost_core::Signature::<C>::deserialize(bytes).unwrap();
+        let verifying_key = frost_core::VerifyingKey::<C>::new(point(&vector["A"]));
+
+        let toml = prover_toml(&msg, &signature, &verifying_key).unwrap();
+        let inputs = parse_prover_toml(&toml);
+        assert_eq!(inputs.len(), 6);
+        assert_eq!(inputs["pub_key_x"], field(&vector["A"][0]));
+        assert_eq!(inputs["pub_key_y"], field(&vector["A"][1]));
+        assert_eq!(inputs["signature_s"], field(&vector["S"]));
+        assert_eq!(inputs["signature_r8_x"], field(&vector["R8"][0]));
+        assert_eq!(inputs["signature_r8_y"], field(&vector["R8"][1]));
+        assert_eq!(inputs["message"], field(&vector["message"]));
+        assert!(noir_verify(&toml));
+
+        let mut tampered = inputs.clone();
+        *tampered.get_mut("message").unwrap() += Fq::from(1u64);
+        assert!(!noir_eddsa_poseidon_verify(
+            tampered["pub_key_x"],
+            tampered["pub_key_y"],
+            tampered["signature_s"],
+            tampered["signature_r8_x"],
+            tampered["signature_r8_y"],
+            tampered["message"],
+        ));
+    }
+}
+
+#[test]
+fn check_threshold_signature_passes_circomlib_verifier() {
+    let mut rng = thread_rng();
+    let msg = Fq::from(1234u64);
+    let message = field_to_message(msg);
+
+    let (shares, pubkeys) =
+        frost::keys::generate_with_dealer(5, 3, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: HashMap<_, _> = shares
+        .into_iter()
+        .take(3)
+        .map(|(id, share)| (id, frost::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let mut nonces = HashMap::new();
+    let mut commitments = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonce, commitment) = frost::round1::commit(key_package.secret_share(), &mut rng);
+        nonces.insert(*id, nonce);
+        commitments.insert(*id, commitment);
+    }
+
+    let signing_package = frost::SigningPackage::new(commitments, &message);
+    let signature_shares: HashMap<_, _> = key_packages
+        .iter()
+        .map(|(id, key_package)| {
+            let share = frost::round2::sign(&signing_package, &nonces[id], key_package).unwrap();
+            (*id, share)
+        })
+        .collect();
+    let signature = frost::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+    assert!(verify_circomlib(msg, &signature, pubkeys.group_public()).is_ok());
+    assert_eq!(
+        witness_input(&message, &signature, pubkeys.group_public())
+            .unwrap()
+            .M,
+        "1234"
+    );
+    assert!(noir_verify(
+        &prover_toml(&message, &signature, pubkeys.group_public()).unwrap()
+    ));
+    assert!(verify_circomlib(Fq::from(1235u64), &signature, pubkeys.group_public()).is_err());
+}
+
+#[test]
+fn check_sign_with_dealer() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<C, _>(rng);
+}
+
+#[test]
+fn check_sign_with_dkg() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<C, _>(rng);
+}
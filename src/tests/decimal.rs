@@ -0,0 +1,142 @@
+use std::str::FromStr;
+
+use ark_ed_on_bn254::Fq;
+use ark_ff::PrimeField;
+use rand::thread_rng;
+use serde_json::{json, Value};
+
+use crate::circomlib::{
+    self as frost, field_to_message, keys::VerifyingShare, round1::SigningCommitments,
+    verify_circomlib, Signature, VerifyingKey,
+};
+use crate::decimal::{Decimal, DecimalPoint, DecimalSignature};
+use crate::tests::circomlib::CIRCOMLIB_EDDSA;
+use crate::*;
+
+fn point(value: &Value) -> DecimalPoint {
+    DecimalPoint {
+        x: value[0].as_str().unwrap().to_owned(),
+        y: value[1].as_str().unwrap().to_owned(),
+    }
+}
+
+#[test]
+fn check_circomlibjs_vectors() {
+    for vector in CIRCOMLIB_EDDSA["vectors"].as_array().unwrap() {
+        let A = point(&vector["A"]);
+        let signature = DecimalSignature {
+            R: point(&vector["R8"]),
+            s: vector["S"].as_str().unwrap().to_owned(),
+        };
+
+        let verifying_key = VerifyingKey::from_decimal(&A).unwrap();
+        let signature_ = Signature::from_decimal(&signature).unwrap();
+        let msg = Fq::from_str(vector["message"].as_str().unwrap()).unwrap();
+        assert!(verify_circomlib(msg, &signature_, &verifying_key).is_ok());
+
+        assert_eq!(verifying_key.to_decimal(), A);
+        assert_eq!(signature_.to_decimal(), signature);
+        assert_eq!(VerifyingShare::from_decimal(&A).unwrap().to_decimal(), A);
+    }
+}
+
+#[test]
+fn check_signing_commitments() {
+    let mut rng = thread_rng();
+    let (shares, _) =
+        frost::keys::generate_with_dealer(3, 2, frost::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let share = shares.values().next().unwrap();
+    let (_, commitments) = frost::round1::commit(share.value(), &mut rng);
+
+    let decimal = commitments.to_decimal();
+    assert_eq!(
+        SigningCommitments::from_decimal(&decimal).unwrap(),
+        commitments
+    );
+}
+
+#[test]
+fn check_rejects_invalid_points() {
+    let A = point(&CIRCOMLIB_EDDSA["vectors"][0]["A"]);
+    let x = Fq::from_str(&A.x).unwrap();
+    let p_minus_one = (-Fq::from(1u64)).into_bigint().to_string();
+
+    let invalid = [
+        // Leading zero.
+        DecimalPoint {
+            x: format!("0{}", A.x),
+            ..A.clone()
+        },
+        // Not on the curve.
+        DecimalPoint {
+            x: (x + Fq::from(1u64)).into_bigint().to_string(),
+            ..A.clone()
+        },
+        // Signed.
+        DecimalPoint {
+            x: format!("+{}", A.x),
+            ..A.clone()
+        },
+        // (0, -1) has order 2.
+        DecimalPoint {
+            x: "0".to_owned(),
+            y: p_minus_one,
+        },
+        // The identity.
+        DecimalPoint {
+            x: "0".to_owned(),
+            y: "1".to_owned(),
+        },
+    ];
+    for point in &invalid {
+        assert!(VerifyingKey::from_decimal(point).is_err());
+    }
+}
+
+#[test]
+fn check_rejects_unreduced_scalar() {
+    let vector = &CIRCOMLIB_EDDSA["vectors"][0];
+    // s + r is still below p, so the sum can be computed in the base field.
+    let s = Fq::from_str(vector["S"].as_str().unwrap()).unwrap()
+        + Fq::from_bigint(Fr::MODULUS).unwrap();
+    let signature = DecimalSignature {
+        R: point(&vector["R8"]),
+        s: s.into_bigint().to_string(),
+    };
+
+    assert_eq!(
+        Signature::from_decimal(&signature),
+        Err(frost_core::Error::MalformedSignature)
+    );
+}
+
+#[test]
+fn check_serde_adapter() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(crate = "self::serde")]
+    struct Inputs {
+        #[serde(with = "crate::decimal")]
+        verifying_key: VerifyingKey,
+        #[serde(with = "crate::decimal")]
+        signature: Signature,
+    }
+
+    let vector = &CIRCOMLIB_EDDSA["vectors"][0];
+    let expected = json!({
+        "verifying_key": { "x": vector["A"][0], "y": vector["A"][1] },
+        "signature": {
+            "R": { "x": vector["R8"][0], "y": vector["R8"][1] },
+            "s": vector["S"],
+        },
+    });
+
+    let inputs: Inputs = serde_json::from_value(expected.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&inputs).unwrap(), expected);
+
+    let message = field_to_message(Fq::from_str(vector["message"].as_str().unwrap()).unwrap());
+    assert!(inputs
+        .verifying_key
+        .verify(&message, &inputs.signature)
+        .is_ok());
+}
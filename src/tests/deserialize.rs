@@ -1,5 +1,8 @@
+#![allow(non_upper_case_globals)]
+
 use ark_ed_on_bn254::Fq;
 use ark_ff::MontFp;
+use ark_serialize::{CanonicalSerialize, Compress};
 
 use crate::{babyjubjub::EdwardsAffine, *};
 
@@ -0,0 +1,461 @@
+use std::collections::{BTreeSet, HashMap};
+
+use frost_core::frost::{
+    keys::{KeyPackage, PublicKeyPackage, SigningShare, VerifiableSecretSharingCommitment},
+    Identifier,
+};
+use frost_core::Error;
+use rand::thread_rng;
+
+use super::helpers::sign;
+use crate::dkg::{self, encrypted, round1, round2, Complaint, Justification};
+use crate::*;
+
+type C = BabyJubJubSha256;
+
+/// The state of a DKG run after round 2, with the packages received by each
+/// participant.
+struct Run {
+    round1_packages: HashMap<Identifier<C>, round1::Package<C>>,
+    secret_packages: HashMap<Identifier<C>, round2::SecretPackage<C>>,
+    received: HashMap<Identifier<C>, HashMap<Identifier<C>, round2::Package<C>>>,
+}
+
+impl Run {
+    fn new(max_signers: u16, min_signers: u16) -> Self {
+        let mut rng = thread_rng();
+        let mut round1_secret_packages = HashMap::new();
+        let mut round1_packages = HashMap::new();
+        for i in 1..=max_signers {
+            let identifier = i.try_into().unwrap();
+            let (secret_package, package) =
+                dkg::part1(identifier, max_signers, min_signers, &mut rng).unwrap();
+            round1_secret_packages.insert(identifier, secret_package);
+            round1_packages.insert(identifier, package);
+        }
+        let mut run = Run {
+            round1_packages,
+            secret_packages: HashMap::new(),
+            received: HashMap::new(),
+        };
+        for (identifier, secret_package) in round1_secret_packages {
+            run.part2(identifier, secret_package);
+        }
+        run
+    }
+
+    fn others(&self, identifier: &Identifier<C>) -> HashMap<Identifier<C>, round1::Package<C>> {
+        let mut others = self.round1_packages.clone();
+        others.remove(identifier);
+        others
+    }
+
+    fn part2(&mut self, identifier: Identifier<C>, secret_package: round1::SecretPackage<C>) {
+        let (secret_package, packages) =
+            dkg::part2(secret_package, &self.others(&identifier)).unwrap();
+        self.secret_packages.insert(identifier, secret_package);
+        for (receiver, package) in packages {
+            self.received
+                .entry(receiver)
+                .or_default()
+                .insert(identifier, package);
+        }
+    }
+
+    fn complaints(&self) -> Vec<Complaint<C>> {
+        self.secret_packages
+            .iter()
+            .flat_map(|(identifier, secret_package)| {
+                dkg::complain(
+                    secret_package,
+                    &self.others(identifier),
+                    &self.received[identifier],
+                )
+            })
+            .collect()
+    }
+
+    fn justifications(&self, complaints: &[Complaint<C>]) -> Vec<Justification<C>> {
+        self.secret_packages
+            .values()
+            .flat_map(|secret_package| dkg::justify(secret_package, complaints))
+            .collect()
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn part3(
+        &self,
+        identifier: &Identifier<C>,
+        complaints: &[Complaint<C>],
+        justifications: &[Justification<C>],
+    ) -> Result<(KeyPackage<C>, PublicKeyPackage<C>, BTreeSet<Identifier<C>>), Error<C>> {
+        dkg::part3(
+            &self.secret_packages[identifier],
+            &self.others(identifier),
+            &self.received[identifier],
+            complaints,
+            justifications,
+        )
+    }
+
+    /// Runs part 3 for the participants not in `excluded`, checks that they
+    /// agree, and returns their key packages and public key package.
+    fn finish(
+        &self,
+        complaints: &[Complaint<C>],
+        justifications: &[Justification<C>],
+        excluded: &BTreeSet<Identifier<C>>,
+    ) -> (Vec<KeyPackage<C>>, PublicKeyPackage<C>) {
+        let mut key_packages = Vec::new();
+        let mut pubkeys = None;
+        for identifier in self.secret_packages.keys() {
+            if excluded.contains(identifier) {
+                continue;
+            }
+            let (key_package, public, disqualified) =
+                self.part3(identifier, complaints, justifications).unwrap();
+            assert_eq!(&disqualified, excluded);
+            assert_eq!(public.signer_pubkeys()[identifier], *key_package.public());
+            assert!(pubkeys.get_or_insert(public.clone()) == &public);
+            key_packages.push(key_package);
+        }
+        (key_packages, pubkeys.unwrap())
+    }
+}
+
+#[test]
+fn check_dkg_without_complaints() {
+    let run = Run::new(5, 3);
+    let complaints = run.complaints();
+    assert!(complaints.is_empty());
+    let (key_packages, pubkeys) = run.finish(&[], &[], &BTreeSet::new());
+    assert_eq!(pubkeys.signer_pubkeys().len(), 5);
+
+    let msg = b"message to sign";
+    let signature = sign(&key_packages[..3], &pubkeys, msg).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+}
+
+#[test]
+fn check_dkg_with_justified_complaint() {
+    let mut run = Run::new(5, 3);
+    let (accuser, accused): (Identifier<C>, Identifier<C>) =
+        (1u16.try_into().unwrap(), 2u16.try_into().unwrap());
+    run.received.get_mut(&accuser).unwrap().insert(
+        accused,
+        round2::Package::new(SigningShare::new(Fr::from(1u64))),
+    );
+
+    let complaints = run.complaints();
+    assert_eq!(complaints, vec![Complaint::new(accuser, accused)]);
+    // The accuser cannot finish with the invalid share.
+    assert_eq!(
+        run.part3(&accuser, &[], &[]),
+        Err(Error::InvalidSecretShare)
+    );
+
+    let justifications = run.justifications(&complaints);
+    assert_eq!(justifications.len(), 1);
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&complaints[0]).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Complaint<C>>(&json).unwrap(),
+            complaints[0]
+        );
+        let json = serde_json::to_string(&justifications[0]).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Justification<C>>(&json).unwrap(),
+            justifications[0]
+        );
+    }
+    let (key_packages, pubkeys) = run.finish(&complaints, &justifications, &BTreeSet::new());
+
+    let msg = b"message to sign";
+    let signature = sign(&key_packages[..3], &pubkeys, msg).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+}
+
+#[test]
+fn check_dkg_skips_invalid_justifications() {
+    let mut run = Run::new(5, 3);
+    let (accuser, accused): (Identifier<C>, Identifier<C>) =
+        (1u16.try_into().unwrap(), 2u16.try_into().unwrap());
+    run.received.get_mut(&accuser).unwrap().insert(
+        accused,
+        round2::Package::new(SigningShare::new(Fr::from(1u64))),
+    );
+    let complaints = run.complaints();
+
+    // The accused posts an invalid justification before the valid one, and
+    // is still qualified by everyone, including the accuser.
+    let valid = run.justifications(&complaints);
+    let justifications = [
+        vec![Justification::new(
+            accuser,
+            accused,
+            SigningShare::new(Fr::from(1u64)),
+        )],
+        valid,
+    ]
+    .concat();
+    let round1_packages = run.round1_packages.clone();
+    assert!(
+        dkg::disqualified(&round1_packages, 3, &complaints, &justifications)
+            .unwrap()
+            .is_empty()
+    );
+    let (key_packages, pubkeys) = run.finish(&complaints, &justifications, &BTreeSet::new());
+
+    let msg = b"message to sign";
+    let signature = sign(&key_packages[..3], &pubkeys, msg).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+}
+
+#[test]
+fn check_dkg_disqualifies_cheaters() {
+    let mut run = Run::new(5, 3);
+    let ids: Vec<Identifier<C>> = (1..=5u16).map(|i| i.try_into().unwrap()).collect();
+
+    // The participant 2 sends invalid shares to 1 and 3 and cannot justify
+    // them.
+    for accuser in [ids[0], ids[2]] {
+        run.received.get_mut(&accuser).unwrap().insert(
+            ids[1],
+            round2::Package::new(SigningShare::new(Fr::from(1u64))),
+        );
+    }
+    let complaints = run.complaints();
+    assert_eq!(complaints.len(), 2);
+    let justifications: Vec<_> = run
+        .justifications(&complaints)
+        .into_iter()
+        .map(|justification| {
+            Justification::new(
+                *justification.accuser(),
+                *justification.accused(),
+                SigningShare::new(Fr::from(1u64)),
+            )
+        })
+        .collect();
+
+    let disqualified = BTreeSet::from([ids[1]]);
+    let round1_packages = run.round1_packages.clone();
+    assert_eq!(
+        dkg::disqualified(&round1_packages, 3, &complaints, &justifications).unwrap(),
+        disqualified
+    );
+    assert_eq!(
+        run.part3(&ids[1], &complaints, &justifications),
+        Err(Error::InvalidSecretShare)
+    );
+    let (key_packages, pubkeys) = run.finish(&complaints, &justifications, &disqualified);
+    assert_eq!(pubkeys.signer_pubkeys().len(), 4);
+    assert!(!pubkeys.signer_pubkeys().contains_key(&ids[1]));
+
+    let msg = b"message to sign";
+    let signature = sign(&key_packages[1..], &pubkeys, msg).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+}
+
+#[test]
+fn check_dkg_disqualifies_invalid_proof_of_knowledge() {
+    let mut rng = thread_rng();
+    let ids: Vec<Identifier<C>> = (1..=3u16).map(|i| i.try_into().unwrap()).collect();
+    let mut secret_packages = HashMap::new();
+    let mut round1_packages = HashMap::new();
+    for identifier in &ids {
+        let (secret_package, package) = dkg::part1(*identifier, 3, 2, &mut rng).unwrap();
+        secret_packages.insert(*identifier, secret_package);
+        round1_packages.insert(*identifier, package);
+    }
+    // The participant 3 reuses the proof of knowledge of 1.
+    let package = &round1_packages[&ids[2]];
+    let tampered = round1::Package::new(
+        package.commitment().clone(),
+        *round1_packages[&ids[0]].proof_of_knowledge(),
+    );
+    round1_packages.insert(ids[2], tampered);
+
+    let mut others = round1_packages.clone();
+    others.remove(&ids[0]);
+    let (secret_package, packages) =
+        dkg::part2(secret_packages.remove(&ids[0]).unwrap(), &others).unwrap();
+    assert_eq!(secret_package.disqualified(), &BTreeSet::from([ids[2]]));
+    assert!(!packages.contains_key(&ids[2]));
+    assert_eq!(
+        dkg::disqualified(&round1_packages, 2, &[], &[]).unwrap(),
+        BTreeSet::from([ids[2]])
+    );
+}
+
+#[test]
+fn check_dkg_fails_without_enough_qualified_participants() {
+    let mut run = Run::new(5, 3);
+    let ids: Vec<Identifier<C>> = (1..=5u16).map(|i| i.try_into().unwrap()).collect();
+
+    // The participants 3, 4 and 5 send an invalid share to 1 and cannot
+    // justify it, which leaves 2 qualified participants for a threshold of 3.
+    for accused in &ids[2..] {
+        run.received.get_mut(&ids[0]).unwrap().insert(
+            *accused,
+            round2::Package::new(SigningShare::new(Fr::from(1u64))),
+        );
+    }
+    let complaints = run.complaints();
+    assert_eq!(complaints.len(), 3);
+    assert_eq!(
+        dkg::disqualified(&run.round1_packages, 3, &complaints, &[]).unwrap(),
+        BTreeSet::from([ids[2], ids[3], ids[4]])
+    );
+    for identifier in &ids[..2] {
+        assert_eq!(
+            run.part3(identifier, &complaints, &[]),
+            Err(Error::InvalidMinSigners)
+        );
+    }
+}
+
+#[test]
+fn check_disqualified_validates_min_signers() {
+    let run = Run::new(3, 2);
+    let ids: Vec<Identifier<C>> = (1..=3u16).map(|i| i.try_into().unwrap()).collect();
+    let mut round1_packages = run.round1_packages.clone();
+    assert_eq!(
+        dkg::disqualified(&round1_packages, 0, &[], &[]),
+        Err(Error::InvalidMinSigners)
+    );
+    assert_eq!(
+        dkg::disqualified(&round1_packages, 4, &[], &[]),
+        Err(Error::InvalidMinSigners)
+    );
+
+    // An empty commitment is disqualified instead of panicking.
+    let package = &round1_packages[&ids[2]];
+    let empty = round1::Package::new(
+        VerifiableSecretSharingCommitment::deserialize(vec![]).unwrap(),
+        *package.proof_of_knowledge(),
+    );
+    round1_packages.insert(ids[2], empty);
+    assert_eq!(
+        dkg::disqualified(&round1_packages, 2, &[], &[]).unwrap(),
+        BTreeSet::from([ids[2]])
+    );
+}
+
+/// Runs the encrypted DKG up to round 2 and returns the round 1 packages, the
+/// secret packages and the round 2 packages received by each participant.
+#[allow(clippy::type_complexity)]
+fn encrypted_run(
+    max_signers: u16,
+    min_signers: u16,
+) -> (
+    HashMap<Identifier<C>, encrypted::round1::Package<C>>,
+    HashMap<Identifier<C>, encrypted::round2::SecretPackage<C>>,
+    HashMap<Identifier<C>, HashMap<Identifier<C>, encrypted::round2::Package<C>>>,
+) {
+    let mut rng = thread_rng();
+    let mut round1_secret_packages = HashMap::new();
+    let mut round1_packages = HashMap::new();
+    for i in 1..=max_signers {
+        let identifier = i.try_into().unwrap();
+        let (secret_package, package) =
+            encrypted::part1(identifier, max_signers, min_signers, &mut rng).unwrap();
+        round1_secret_packages.insert(identifier, secret_package);
+        round1_packages.insert(identifier, package);
+    }
+    let mut secret_packages = HashMap::new();
+    let mut received: HashMap<_, HashMap<_, _>> = HashMap::new();
+    for (identifier, secret_package) in round1_secret_packages {
+        let mut others = round1_packages.clone();
+        others.remove(&identifier);
+        let (secret_package, packages) =
+            encrypted::part2(secret_package, &others, &mut rng).unwrap();
+        secret_packages.insert(identifier, secret_package);
+        for (receiver, package) in packages {
+            received
+                .entry(receiver)
+                .or_default()
+                .insert(identifier, package);
+        }
+    }
+    (round1_packages, secret_packages, received)
+}
+
+#[test]
+fn check_encrypted_dkg() {
+    let (round1_packages, secret_packages, mut received) = encrypted_run(5, 3);
+    let ids: Vec<Identifier<C>> = (1..=5u16).map(|i| i.try_into().unwrap()).collect();
+    let others = |identifier: &Identifier<C>| {
+        let mut others = round1_packages.clone();
+        others.remove(identifier);
+        others
+    };
+
+    // The package from 2 to 1 is replaced by the one from 2 to 3, which 1
+    // cannot decrypt.
+    let misdirected = received[&ids[2]][&ids[1]].clone();
+    received
+        .get_mut(&ids[0])
+        .unwrap()
+        .insert(ids[1], misdirected);
+
+    #[cfg(feature = "serde")]
+    {
+        let package = &round1_packages[&ids[0]];
+        let json = serde_json::to_string(package).unwrap();
+        assert_eq!(
+            &serde_json::from_str::<encrypted::round1::Package<C>>(&json).unwrap(),
+            package
+        );
+        let package = &received[&ids[0]][&ids[1]];
+        let json = serde_json::to_string(package).unwrap();
+        assert_eq!(
+            &serde_json::from_str::<encrypted::round2::Package<C>>(&json).unwrap(),
+            package
+        );
+    }
+
+    let complaints: Vec<_> = ids
+        .iter()
+        .flat_map(|identifier| {
+            encrypted::complain(
+                &secret_packages[identifier],
+                &others(identifier),
+                &received[identifier],
+            )
+        })
+        .collect();
+    assert_eq!(complaints, vec![Complaint::new(ids[0], ids[1])]);
+    let justifications: Vec<_> = ids
+        .iter()
+        .flat_map(|identifier| encrypted::justify(&secret_packages[identifier], &complaints))
+        .collect();
+    assert_eq!(justifications.len(), 1);
+    assert!(
+        encrypted::disqualified(&round1_packages, 3, &complaints, &justifications)
+            .unwrap()
+            .is_empty()
+    );
+
+    let mut key_packages = Vec::new();
+    let mut pubkeys = None;
+    for identifier in &ids {
+        let (key_package, public, disqualified) = encrypted::part3(
+            &secret_packages[identifier],
+            &others(identifier),
+            &received[identifier],
+            &complaints,
+            &justifications,
+        )
+        .unwrap();
+        assert!(disqualified.is_empty());
+        assert!(pubkeys.get_or_insert(public.clone()) == &public);
+        key_packages.push(key_package);
+    }
+    let pubkeys = pubkeys.unwrap();
+
+    let msg = b"message to sign";
+    let signature = sign(&key_packages[2..], &pubkeys, msg).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+}
@@ -1,7 +1,13 @@
+#![allow(non_upper_case_globals)]
+
+use ark_ec::CurveGroup;
 use ark_ed_on_bn254::Fq;
 use ark_ff::MontFp;
 
-use crate::{babyjubjub::EdwardsAffine, *};
+use crate::{
+    babyjubjub::{EdwardsAffine, EdwardsProjective},
+    *,
+};
 
 #[test]
 fn membership_zero_one() {
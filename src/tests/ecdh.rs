@@ -0,0 +1,79 @@
+use std::collections::{BTreeSet, HashMap};
+
+use frost_core::{SigningKey, VerifyingKey};
+use rand::thread_rng;
+
+use super::helpers::generate;
+use crate::dleq::CombineError;
+use crate::{ecdh, ecies, *};
+
+type C = BabyJubJubSha256;
+
+#[test]
+fn check_threshold_ecdh() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let ids: Vec<_> = key_packages.keys().copied().collect();
+    let peer = SigningKey::<C>::new(&mut rng);
+    let peer_public = VerifyingKey::from(peer);
+
+    let mut shares: HashMap<_, _> = key_packages
+        .iter()
+        .take(3)
+        .map(|(identifier, key_package)| {
+            let share = ecdh::shared_secret_share(key_package, &peer_public, &mut rng);
+            (*identifier, share)
+        })
+        .collect();
+    let shared_secret = ecdh::combine(&peer_public, &shares, &pubkeys, 3).unwrap();
+    let expected = pubkeys.group_public().to_element() * peer.to_scalar();
+    assert_eq!(shared_secret.element(), &expected);
+    assert_eq!(
+        shared_secret.derive_key(b"app"),
+        ecdh::combine(&peer_public, &shares, &pubkeys, 3)
+            .unwrap()
+            .derive_key(b"app")
+    );
+    assert_ne!(
+        shared_secret.derive_key(b"app"),
+        shared_secret.derive_key(b"other app")
+    );
+
+    // A share computed with another public key is rejected.
+    let other = VerifyingKey::from(SigningKey::<C>::new(&mut rng));
+    shares.insert(
+        ids[0],
+        ecdh::shared_secret_share(&key_packages[&ids[0]], &other, &mut rng),
+    );
+    assert_eq!(
+        ecdh::combine(&peer_public, &shares, &pubkeys, 3),
+        Err(CombineError::InvalidShares(BTreeSet::from([ids[0]])))
+    );
+}
+
+#[test]
+fn check_threshold_ecies_decryption() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(3, 2);
+    let ciphertext =
+        ecies::encrypt(pubkeys.group_public(), b"aad", b"to the group", &mut rng).unwrap();
+
+    let shares: HashMap<_, _> = key_packages
+        .iter()
+        .skip(1)
+        .map(|(identifier, key_package)| {
+            let share =
+                ecdh::shared_secret_share(key_package, ciphertext.ephemeral_key(), &mut rng);
+            (*identifier, share)
+        })
+        .collect();
+    let shared_secret = ecdh::combine(ciphertext.ephemeral_key(), &shares, &pubkeys, 2).unwrap();
+    assert_eq!(
+        ecdh::decrypt(&shared_secret, pubkeys.group_public(), b"aad", &ciphertext).unwrap(),
+        b"to the group"
+    );
+    assert_eq!(
+        ecdh::decrypt(&shared_secret, pubkeys.group_public(), b"", &ciphertext),
+        Err(ecies::EciesError::DecryptionFailed)
+    );
+}
@@ -0,0 +1,119 @@
+use frost_core::{SigningKey, VerifyingKey};
+use rand::thread_rng;
+
+use super::helpers::generate;
+use crate::babyjubjub::{EdwardsAffine, EdwardsProjective};
+use crate::ecies::{self, Ciphertext, EciesError};
+use crate::*;
+
+type C = BabyJubJubSha256;
+
+#[test]
+fn check_encrypt_and_decrypt() {
+    let mut rng = thread_rng();
+    let secret = SigningKey::<C>::new(&mut rng);
+    let recipient = VerifyingKey::from(secret);
+    let ciphertext = ecies::encrypt(&recipient, b"aad", b"plaintext", &mut rng).unwrap();
+    assert_eq!(
+        ecies::decrypt(&secret, b"aad", &ciphertext).unwrap(),
+        b"plaintext"
+    );
+    assert_eq!(
+        ecies::decrypt(&secret, b"other aad", &ciphertext),
+        Err(EciesError::DecryptionFailed)
+    );
+    let other = SigningKey::<C>::new(&mut rng);
+    assert_eq!(
+        ecies::decrypt(&other, b"aad", &ciphertext),
+        Err(EciesError::DecryptionFailed)
+    );
+}
+
+#[test]
+fn check_authenticated_encryption() {
+    let mut rng = thread_rng();
+    let sender = SigningKey::<C>::new(&mut rng);
+    let secret = SigningKey::<C>::new(&mut rng);
+    let recipient = VerifyingKey::from(secret);
+    let ciphertext =
+        ecies::encrypt_authenticated(&sender, &recipient, b"aad", b"plaintext", &mut rng).unwrap();
+    assert_eq!(
+        ecies::decrypt_authenticated(&secret, &VerifyingKey::from(sender), b"aad", &ciphertext)
+            .unwrap(),
+        b"plaintext"
+    );
+
+    // Another sender is rejected, and so is an anonymous decryption.
+    let other = VerifyingKey::from(SigningKey::<C>::new(&mut rng));
+    assert_eq!(
+        ecies::decrypt_authenticated(&secret, &other, b"aad", &ciphertext),
+        Err(EciesError::DecryptionFailed)
+    );
+    assert_eq!(
+        ecies::decrypt(&secret, b"aad", &ciphertext),
+        Err(EciesError::DecryptionFailed)
+    );
+}
+
+#[test]
+fn check_encrypt_to_shares() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(3, 2);
+    let sender = key_packages.values().next().unwrap();
+    for (identifier, key_package) in &key_packages {
+        let recipient = &pubkeys.signer_pubkeys()[identifier];
+        let ciphertext = ecies::encrypt(recipient, b"", b"to a share", &mut rng).unwrap();
+        assert_eq!(
+            ecies::decrypt(key_package, b"", &ciphertext).unwrap(),
+            b"to a share"
+        );
+        assert_eq!(
+            ecies::decrypt(key_package.secret_share(), b"", &ciphertext).unwrap(),
+            b"to a share"
+        );
+
+        let ciphertext =
+            ecies::encrypt_authenticated(sender, recipient, b"", b"from a share", &mut rng)
+                .unwrap();
+        assert_eq!(
+            ecies::decrypt_authenticated(key_package, sender.public(), b"", &ciphertext).unwrap(),
+            b"from a share"
+        );
+    }
+}
+
+#[test]
+fn check_ciphertext_serialization() {
+    let mut rng = thread_rng();
+    let secret = SigningKey::<C>::new(&mut rng);
+    let ciphertext =
+        ecies::encrypt(&VerifyingKey::from(secret), b"", b"plaintext", &mut rng).unwrap();
+    let bytes = ciphertext.serialize();
+    assert_eq!(bytes.len(), 32 + 9 + 16);
+    assert_eq!(Ciphertext::<C>::deserialize(&bytes).unwrap(), ciphertext);
+
+    // The identity, a point of order 2 and truncated bytes are rejected.
+    let mut identity = bytes.clone();
+    identity[..32].copy_from_slice(&BabyJubJubGroup::serialize(&BabyJubJubGroup::identity()));
+    let order_two = EdwardsProjective::from(EdwardsAffine {
+        x: Fq::from(0u64),
+        y: -Fq::from(1u64),
+    });
+    let mut small_order = bytes.clone();
+    small_order[..32].copy_from_slice(&BabyJubJubGroup::serialize(&order_two));
+    for bytes in [identity, small_order, bytes[..40].to_vec()] {
+        assert_eq!(
+            Ciphertext::<C>::deserialize(&bytes),
+            Err(EciesError::MalformedCiphertext)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&ciphertext).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Ciphertext<C>>(&json).unwrap(),
+            ciphertext
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::{BTreeSet, HashMap};
+
+use frost_core::{Error, Group, SigningKey, VerifyingKey};
+use rand::thread_rng;
+
+use super::helpers::generate;
+use crate::dleq::CombineError;
+use crate::elgamal::{self, DecryptionShare};
+use crate::*;
+
+type C = BabyJubJubSha256;
+
+#[test]
+fn check_encrypt_and_decrypt() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let message = BabyJubJubGroup::generator() * Fr::from(42u64);
+    let ciphertext = elgamal::encrypt(&VerifyingKey::from(signing_key), &message, &mut rng);
+    assert_eq!(elgamal::decrypt(&signing_key, &ciphertext), message);
+}
+
+#[test]
+fn check_threshold_decryption() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let ids: Vec<_> = key_packages.keys().copied().collect();
+    let message = BabyJubJubGroup::generator() * Fr::from(42u64);
+    let ciphertext = elgamal::encrypt(pubkeys.group_public(), &message, &mut rng);
+
+    let shares: HashMap<_, _> = key_packages
+        .iter()
+        .map(|(identifier, key_package)| {
+            let share = elgamal::decryption_share(key_package, &ciphertext, &mut rng);
+            (*identifier, share)
+        })
+        .collect();
+    assert_eq!(
+        elgamal::combine(&ciphertext, &shares, &pubkeys, 3).unwrap(),
+        message
+    );
+    let three: HashMap<_, _> = ids[2..].iter().map(|id| (*id, shares[id])).collect();
+    assert_eq!(
+        elgamal::combine(&ciphertext, &three, &pubkeys, 3).unwrap(),
+        message
+    );
+    let two: HashMap<_, _> = ids[3..].iter().map(|id| (*id, shares[id])).collect();
+    assert_eq!(
+        elgamal::combine(&ciphertext, &two, &pubkeys, 3),
+        Err(CombineError::Frost(Error::IncorrectNumberOfShares))
+    );
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&ciphertext).unwrap();
+        assert_eq!(
+            serde_json::from_str::<elgamal::Ciphertext<C>>(&json).unwrap(),
+            ciphertext
+        );
+        let json = serde_json::to_string(&shares[&ids[0]]).unwrap();
+        assert_eq!(
+            serde_json::from_str::<DecryptionShare<C>>(&json).unwrap(),
+            shares[&ids[0]]
+        );
+    }
+}
+
+#[test]
+fn check_combine_names_invalid_shares() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let ids: Vec<_> = key_packages.keys().copied().collect();
+    let message = BabyJubJubGroup::generator() * Fr::from(7u64);
+    let ciphertext = elgamal::encrypt(pubkeys.group_public(), &message, &mut rng);
+    let mut shares: HashMap<_, _> = key_packages
+        .iter()
+        .map(|(identifier, key_package)| {
+            let share = elgamal::decryption_share(key_package, &ciphertext, &mut rng);
+            (*identifier, share)
+        })
+        .collect();
+
+    // A share with a wrong element, a share sent under another identifier,
+    // and a share of a ciphertext with a different ephemeral key.
+    let share = shares[&ids[0]];
+    shares.insert(
+        ids[0],
+        DecryptionShare::new(
+            *share.element() + BabyJubJubGroup::generator(),
+            *share.proof(),
+        ),
+    );
+    shares.insert(ids[1], shares[&ids[2]]);
+    let other = elgamal::encrypt(pubkeys.group_public(), &message, &mut rng);
+    shares.insert(
+        ids[3],
+        elgamal::decryption_share(&key_packages[&ids[3]], &other, &mut rng),
+    );
+
+    let err = elgamal::combine(&ciphertext, &shares, &pubkeys, 3).unwrap_err();
+    assert_eq!(
+        err,
+        CombineError::InvalidShares(BTreeSet::from([ids[0], ids[1], ids[3]]))
+    );
+    assert_eq!(err.culprits(), vec![ids[0], ids[1], ids[3]]);
+}
+
+#[test]
+fn check_discrete_log() {
+    let generator = BabyJubJubGroup::generator();
+    for bound in [0u64, 1, 2, 15, 16, 17, 1000] {
+        for m in [0, bound / 2, bound] {
+            let point = generator * Fr::from(m);
+            assert_eq!(elgamal::discrete_log(&point, bound), Some(m));
+        }
+        let point = generator * Fr::from(bound + 1);
+        assert_eq!(elgamal::discrete_log(&point, bound), None);
+    }
+    assert_eq!(elgamal::discrete_log(&-generator, 1000), None);
+}
+
+#[test]
+fn check_encrypted_tally() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let votes = [1u64, 0, 1, 1, 0, 1, 1, 0, 0, 1];
+    let ballots: Vec<_> = votes
+        .iter()
+        .map(|vote| elgamal::encrypt_exponent(pubkeys.group_public(), *vote, &mut rng))
+        .collect();
+    let mut tally = ballots[0];
+    for ballot in &ballots[1..] {
+        tally += *ballot;
+    }
+    assert_eq!(
+        tally,
+        ballots
+            .into_iter()
+            .reduce(|sum, ballot| sum + ballot)
+            .unwrap()
+    );
+
+    let shares: HashMap<_, _> = key_packages
+        .iter()
+        .take(3)
+        .map(|(identifier, key_package)| {
+            let share = elgamal::decryption_share(key_package, &tally, &mut rng);
+            (*identifier, share)
+        })
+        .collect();
+    let total = elgamal::combine(&tally, &shares, &pubkeys, 3).unwrap();
+    assert_eq!(elgamal::discrete_log(&total, votes.len() as u64), Some(6));
+
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let ciphertext = elgamal::encrypt_exponent(&VerifyingKey::from(signing_key), 12345, &mut rng);
+    assert_eq!(
+        elgamal::discrete_log(&elgamal::decrypt(&signing_key, &ciphertext), 1 << 16),
+        Some(12345)
+    );
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use frost_core::frost::{keys, Identifier};
+use frost_core::Error;
+use rand::thread_rng;
+
+use super::helpers::{generate, sign};
+use crate::*;
+use crate::{enrollment, refresh};
+
+type C = BabyJubJubSha256;
+
+#[test]
+fn check_enroll_participant() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let helpers: Vec<_> = key_packages.keys().copied().take(3).collect();
+    let participant: Identifier<C> = 10u16.try_into().unwrap();
+
+    let mut received_deltas: HashMap<_, Vec<_>> = HashMap::new();
+    for helper in &helpers {
+        let deltas =
+            enrollment::enroll_step_1(&helpers, &key_packages[helper], &mut rng, participant)
+                .unwrap();
+        for (receiver, delta) in deltas {
+            received_deltas.entry(receiver).or_default().push(delta);
+        }
+    }
+    let sigmas: Vec<_> = helpers
+        .iter()
+        .map(|helper| enrollment::enroll_step_2::<C>(&received_deltas[helper]))
+        .collect();
+
+    let (key_package, new_pubkeys) =
+        enrollment::enroll_step_3(&sigmas, participant, 3, &pubkeys).unwrap();
+    assert_eq!(
+        new_pubkeys,
+        enrollment::add_participant(&pubkeys, participant).unwrap()
+    );
+    assert_eq!(new_pubkeys.group_public(), pubkeys.group_public());
+    assert_eq!(new_pubkeys.signer_pubkeys().len(), 6);
+
+    let msg = b"message to sign";
+    let others: Vec<_> = key_packages.values().skip(3).collect();
+    let signature = sign([&key_package, others[0], others[1]], &new_pubkeys, msg).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+
+    // A tampered sigma gives a share that does not match the group.
+    let mut tampered = sigmas.clone();
+    tampered[0] += Fr::from(1u64);
+    assert_eq!(
+        enrollment::enroll_step_3(&tampered, participant, 3, &pubkeys),
+        Err(Error::InvalidSecretShare)
+    );
+
+    assert_eq!(
+        enrollment::enroll_step_1(&helpers, &key_packages[&helpers[0]], &mut rng, helpers[1]),
+        Err(Error::DuplicatedIdentifier)
+    );
+    assert_eq!(
+        enrollment::enroll_step_1(
+            &helpers[..2],
+            &key_packages[&helpers[0]],
+            &mut rng,
+            participant
+        ),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+    assert_eq!(
+        enrollment::add_participant(&pubkeys, helpers[0]),
+        Err(Error::DuplicatedIdentifier)
+    );
+}
+
+#[test]
+fn check_disenroll_participant() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let removed = *key_packages.keys().next().unwrap();
+
+    let (refreshing_shares, new_pubkeys) =
+        enrollment::disenroll(&pubkeys, &[removed], 3, &mut rng).unwrap();
+    assert_eq!(new_pubkeys.group_public(), pubkeys.group_public());
+    assert_eq!(new_pubkeys.signer_pubkeys().len(), 4);
+    assert!(!refreshing_shares.contains_key(&removed));
+
+    let new_key_packages: Vec<_> = key_packages
+        .values()
+        .skip(1)
+        .map(|key_package| {
+            refresh::refresh_share(
+                refreshing_shares[key_package.identifier()].clone(),
+                key_package,
+            )
+            .unwrap()
+        })
+        .collect();
+
+    let msg = b"message to sign";
+    let signature = sign(&new_key_packages[..3], &new_pubkeys, msg).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+
+    // The removed share no longer combines with the refreshed ones.
+    let mixed = [
+        key_packages[&removed].clone(),
+        new_key_packages[0].clone(),
+        new_key_packages[1].clone(),
+    ];
+    assert_ne!(
+        keys::reconstruct(&mixed).unwrap(),
+        keys::reconstruct(&new_key_packages[..3]).unwrap()
+    );
+
+    assert_eq!(
+        enrollment::remove_participants(&new_pubkeys, &[removed]),
+        Err(Error::UnknownIdentifier)
+    );
+    let many: Vec<_> = key_packages.keys().copied().take(3).collect();
+    assert_eq!(
+        enrollment::disenroll(&pubkeys, &many, 3, &mut rng),
+        Err(Error::InvalidMinSigners)
+    );
+}
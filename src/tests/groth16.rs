@@ -0,0 +1,49 @@
+use frost_core::{SigningKey, VerifyingKey};
+use rand::thread_rng;
+
+use crate::groth16::{self, Proof, ProofError, ProofVerifyingKey, ProvingKey};
+use crate::*;
+
+type C = BabyJubJubPoseidon;
+
+#[test]
+fn check_prove_and_verify() {
+    let mut rng = thread_rng();
+    let msg = b"message to sign";
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let verifying_key = VerifyingKey::from(&signing_key);
+    let signature = signing_key.sign(&mut rng, msg);
+    let other_key = VerifyingKey::from(&SigningKey::<C>::new(&mut rng));
+
+    let (proving_key, proof_verifying_key) = groth16::setup::<C, _>(&mut rng).unwrap();
+    let proof = groth16::prove(&proving_key, &verifying_key, msg, &signature, &mut rng).unwrap();
+
+    assert!(groth16::verify(&proof_verifying_key, &verifying_key, msg, &proof).is_ok());
+    assert_eq!(
+        groth16::verify(
+            &proof_verifying_key,
+            &verifying_key,
+            b"other message",
+            &proof
+        ),
+        Err(ProofError::InvalidProof)
+    );
+    assert_eq!(
+        groth16::verify(&proof_verifying_key, &other_key, msg, &proof),
+        Err(ProofError::InvalidProof)
+    );
+    assert_eq!(
+        groth16::prove(&proving_key, &other_key, msg, &signature, &mut rng),
+        Err(ProofError::InvalidSignature)
+    );
+
+    // Serialization round trips.
+    assert_eq!(
+        ProvingKey::<C>::deserialize(&proving_key.serialize()).unwrap(),
+        proving_key
+    );
+    let proof_verifying_key =
+        ProofVerifyingKey::<C>::deserialize(&proof_verifying_key.serialize()).unwrap();
+    let proof = Proof::<C>::deserialize(&proof.serialize()).unwrap();
+    assert!(groth16::verify(&proof_verifying_key, &verifying_key, msg, &proof).is_ok());
+}
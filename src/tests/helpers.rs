@@ -0,0 +1,64 @@
+//! Helpers shared by the key management tests.
+
+use std::collections::{BTreeMap, HashMap};
+
+use frost_core::{
+    frost::{
+        self,
+        keys::{KeyPackage, PublicKeyPackage},
+        Identifier,
+    },
+    Ciphersuite, Error, Signature,
+};
+use rand::thread_rng;
+
+/// Generates key packages for `max_signers` participants with a trusted
+/// dealer.
+pub fn generate<C: Ciphersuite>(
+    max_signers: u16,
+    min_signers: u16,
+) -> (BTreeMap<Identifier<C>, KeyPackage<C>>, PublicKeyPackage<C>) {
+    let (shares, pubkeys) = frost::keys::generate_with_dealer(
+        max_signers,
+        min_signers,
+        frost::keys::IdentifierList::Default,
+        &mut thread_rng(),
+    )
+    .unwrap();
+    let key_packages = shares
+        .into_iter()
+        .map(|(id, share)| (id, KeyPackage::try_from(share).unwrap()))
+        .collect();
+    (key_packages, pubkeys)
+}
+
+/// Signs `msg` with `key_packages` and aggregates the signature shares with
+/// `pubkeys`.
+pub fn sign<'a, C: Ciphersuite + 'a>(
+    key_packages: impl IntoIterator<Item = &'a KeyPackage<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+    msg: &[u8],
+) -> Result<Signature<C>, Error<C>> {
+    let mut rng = thread_rng();
+    let key_packages: Vec<_> = key_packages.into_iter().collect();
+
+    let mut nonces = HashMap::new();
+    let mut commitments = BTreeMap::new();
+    for key_package in &key_packages {
+        let (nonce, commitment) = frost::round1::commit(key_package.secret_share(), &mut rng);
+        nonces.insert(*key_package.identifier(), nonce);
+        commitments.insert(*key_package.identifier(), commitment);
+    }
+
+    let signing_package = frost::SigningPackage::new(commitments, msg);
+    let mut signature_shares = HashMap::new();
+    for key_package in &key_packages {
+        let share = frost::round2::sign(
+            &signing_package,
+            &nonces[key_package.identifier()],
+            key_package,
+        )?;
+        signature_shares.insert(*key_package.identifier(), share);
+    }
+    frost::aggregate(&signing_package, &signature_shares, pubkeys)
+}
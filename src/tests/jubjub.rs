@@ -0,0 +1,151 @@
+use ark_ec::{twisted_edwards::Affine, AffineRepr, CurveGroup};
+use ark_ed_on_bls12_381::{Fq, Fr, JubjubConfig};
+use ark_ff::{One, Zero};
+use ark_serialize::CanonicalSerialize;
+use lazy_static::lazy_static;
+use rand::thread_rng;
+use serde_json::Value;
+
+use crate::jubjub::JubjubGroup;
+use crate::*;
+
+lazy_static! {
+    pub static ref ELEMENTS: Value =
+        serde_json::from_str(include_str!("../../tests/helpers/elements.json").trim()).unwrap();
+    pub static ref REPAIR_SHARE: Value =
+        serde_json::from_str(include_str!("../../tests/helpers/repair-share-jubjub.json").trim())
+            .unwrap();
+}
+
+#[test]
+fn check_cofactor() {
+    assert_eq!(JubjubGroup::cofactor(), Fr::from(8u64));
+    assert_eq!(BabyJubJubGroup::cofactor(), crate::Fr::from(8u64));
+}
+
+#[test]
+fn check_deserialize_rejects_small_order_point() {
+    // (0, -1) has order 2, so it is on the curve but outside of the
+    // prime-order subgroup.
+    let point = Affine::<JubjubConfig>::new_unchecked(Fq::zero(), -Fq::one());
+    let mut bytes = [0u8; 32];
+    point.serialize_compressed(&mut bytes[..]).unwrap();
+
+    assert_eq!(
+        JubjubGroup::deserialize(&bytes),
+        Err(GroupError::MalformedElement)
+    );
+
+    // The generator shifted by the same torsion point is rejected as well.
+    let shifted = (JubjubGroup::generator() + point.into_group()).into_affine();
+    assert!(shifted.is_on_curve());
+    let mut bytes = [0u8; 32];
+    shifted.serialize_compressed(&mut bytes[..]).unwrap();
+
+    assert_eq!(
+        JubjubGroup::deserialize(&bytes),
+        Err(GroupError::MalformedElement)
+    );
+}
+
+#[test]
+fn check_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::batch_verify::<JubjubSha256, _>(rng);
+}
+
+#[test]
+fn check_bad_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::bad_batch_verify::<JubjubSha256, _>(rng);
+}
+
+#[test]
+fn empty_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::empty_batch_verify::<JubjubSha256, _>(rng);
+}
+
+#[test]
+fn check_serialization_of_coefficient_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::coefficient_commitment::check_serialization_of_coefficient_commitment::<
+        JubjubSha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_create_coefficient_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::coefficient_commitment::check_create_coefficient_commitment::<JubjubSha256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_create_coefficient_commitment_error() {
+    frost_core::tests::coefficient_commitment::check_create_coefficient_commitment_error::<
+        JubjubSha256,
+    >(&ELEMENTS);
+}
+
+#[test]
+fn check_get_value_of_coefficient_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::coefficient_commitment::check_get_value_of_coefficient_commitment::<
+        JubjubSha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_serialize_vss_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_serialize_vss_commitment::<JubjubSha256, _>(rng);
+}
+
+#[test]
+fn check_deserialize_vss_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_deserialize_vss_commitment::<JubjubSha256, _>(rng);
+}
+
+#[test]
+fn check_deserialize_vss_commitment_error() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_deserialize_vss_commitment_error::<JubjubSha256, _>(
+        rng, &ELEMENTS,
+    );
+}
+
+#[test]
+fn check_repair_share_step_1() {
+    let rng = thread_rng();
+
+    frost_core::tests::repairable::check_repair_share_step_1::<JubjubSha256, _>(rng);
+}
+
+#[test]
+fn check_repair_share_step_2() {
+    frost_core::tests::repairable::check_repair_share_step_2::<JubjubSha256>(&REPAIR_SHARE);
+}
+
+#[test]
+fn check_repair_share_step_3() {
+    let rng = thread_rng();
+    frost_core::tests::repairable::check_repair_share_step_3::<JubjubSha256, _>(rng, &REPAIR_SHARE);
+}
+
+#[test]
+fn check_repair_share_step_1_fails_with_invalid_min_signers() {
+    let rng = thread_rng();
+    frost_core::tests::repairable::check_repair_share_step_1_fails_with_invalid_min_signers::<
+        JubjubSha256,
+        _,
+    >(rng);
+}
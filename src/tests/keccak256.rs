@@ -0,0 +1,148 @@
+use ark_ff::{Field as _, PrimeField};
+use lazy_static::lazy_static;
+use rand::thread_rng;
+use serde_json::Value;
+
+use crate::keccak256::{hash_to_array, hash_to_scalar};
+use crate::*;
+
+lazy_static! {
+    pub static ref ELEMENTS: Value =
+        serde_json::from_str(include_str!("../../tests/helpers/elements.json").trim()).unwrap();
+    pub static ref REPAIR_SHARE: Value =
+        serde_json::from_str(include_str!("../../tests/helpers/repair-share.json").trim()).unwrap();
+}
+
+#[test]
+fn check_keccak256_is_not_sha3() {
+    // keccak256("") as returned by the EVM; SHA3-256("") differs.
+    assert_eq!(
+        hex::encode(hash_to_array(&[])),
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+}
+
+#[test]
+fn check_hash_to_scalar_matches_solidity_reduction() {
+    let dst = b"FROST-babyjubjub-KECCAK256-v1chal";
+    let msg = b"message to sign";
+
+    // addmod(mulmod(h0, 2^256 mod r, r), h1, r)
+    let h0 = Fr::from_be_bytes_mod_order(&hash_to_array(&[dst, msg, &[0]]));
+    let h1 = Fr::from_be_bytes_mod_order(&hash_to_array(&[dst, msg, &[1]]));
+    let r256 = Fr::from(2u64).pow([256]);
+
+    assert_eq!(hash_to_scalar(dst, msg), h0 * r256 + h1);
+    assert_eq!(BabyJubJubKeccak256::H2(msg), h0 * r256 + h1);
+}
+
+#[test]
+fn check_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::batch_verify::<BabyJubJubKeccak256, _>(rng);
+}
+
+#[test]
+fn check_bad_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::bad_batch_verify::<BabyJubJubKeccak256, _>(rng);
+}
+
+#[test]
+fn empty_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::empty_batch_verify::<BabyJubJubKeccak256, _>(rng);
+}
+
+#[test]
+fn check_serialization_of_coefficient_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::coefficient_commitment::check_serialization_of_coefficient_commitment::<
+        BabyJubJubKeccak256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_create_coefficient_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::coefficient_commitment::check_create_coefficient_commitment::<
+        BabyJubJubKeccak256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_create_coefficient_commitment_error() {
+    frost_core::tests::coefficient_commitment::check_create_coefficient_commitment_error::<
+        BabyJubJubKeccak256,
+    >(&ELEMENTS);
+}
+
+#[test]
+fn check_get_value_of_coefficient_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::coefficient_commitment::check_get_value_of_coefficient_commitment::<
+        BabyJubJubKeccak256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_serialize_vss_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_serialize_vss_commitment::<BabyJubJubKeccak256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_deserialize_vss_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_deserialize_vss_commitment::<BabyJubJubKeccak256, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_deserialize_vss_commitment_error() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_deserialize_vss_commitment_error::<
+        BabyJubJubKeccak256,
+        _,
+    >(rng, &ELEMENTS);
+}
+
+#[test]
+fn check_repair_share_step_1() {
+    let rng = thread_rng();
+
+    frost_core::tests::repairable::check_repair_share_step_1::<BabyJubJubKeccak256, _>(rng);
+}
+
+#[test]
+fn check_repair_share_step_2() {
+    frost_core::tests::repairable::check_repair_share_step_2::<BabyJubJubKeccak256>(&REPAIR_SHARE);
+}
+
+#[test]
+fn check_repair_share_step_3() {
+    let rng = thread_rng();
+    frost_core::tests::repairable::check_repair_share_step_3::<BabyJubJubKeccak256, _>(
+        rng,
+        &REPAIR_SHARE,
+    );
+}
+
+#[test]
+fn check_repair_share_step_1_fails_with_invalid_min_signers() {
+    let rng = thread_rng();
+    frost_core::tests::repairable::check_repair_share_step_1_fails_with_invalid_min_signers::<
+        BabyJubJubKeccak256,
+        _,
+    >(rng);
+}
@@ -0,0 +1,135 @@
+use std::str::FromStr;
+
+use ark_ed_on_bn254::Fq;
+use ark_ff::{BigInteger, PrimeField};
+use lazy_static::lazy_static;
+use serde_json::Value;
+
+use crate::babyjubjub::EdwardsAffine;
+use crate::circomlib::{keys::VerifyingShare, round1::NonceCommitment, Signature, VerifyingKey};
+use crate::pack::{pack_point, unpack_point};
+use crate::*;
+
+lazy_static! {
+    pub static ref CIRCOMLIB_PACK: Value =
+        serde_json::from_str(include_str!("../../tests/helpers/circomlib-pack.json").trim())
+            .unwrap();
+}
+
+fn field(value: &Value) -> Fq {
+    Fq::from_str(value.as_str().unwrap()).unwrap()
+}
+
+fn point(value: &Value) -> EdwardsAffine {
+    EdwardsAffine::new(field(&value[0]), field(&value[1]))
+}
+
+fn packed<const N: usize>(value: &Value) -> [u8; N] {
+    hex::decode(value.as_str().unwrap())
+        .unwrap()
+        .try_into()
+        .unwrap()
+}
+
+#[test]
+fn check_pack_point_vectors() {
+    for vector in CIRCOMLIB_PACK["points"].as_array().unwrap() {
+        let point = point(&vector["point"]);
+        let packed = packed(&vector["packed"]);
+
+        assert_eq!(pack_point(&point), packed);
+        assert_eq!(unpack_point(&packed), Some(point));
+    }
+}
+
+#[test]
+fn check_pack_signature_vectors() {
+    for vector in CIRCOMLIB_PACK["signatures"].as_array().unwrap() {
+        let R8 = BabyJubJubGroup::serialize(&point(&vector["R8"]).into());
+        let S = Fr::from_str(vector["S"].as_str().unwrap()).unwrap();
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&R8);
+        bytes[32..].copy_from_slice(&BabyJubJubScalarField::serialize(&S));
+        let signature = Signature::deserialize(bytes).unwrap();
+        let packed = packed(&vector["packed"]);
+
+        assert_eq!(signature.pack(), packed);
+        assert_eq!(Signature::unpack(&packed).unwrap(), signature);
+        // The packed encoding coincides with the default serialization.
+        assert_eq!(signature.serialize(), packed);
+    }
+}
+
+#[test]
+fn check_pack_elements() {
+    for vector in CIRCOMLIB_PACK["points"].as_array().unwrap() {
+        let bytes = BabyJubJubGroup::serialize(&point(&vector["point"]).into());
+        let packed = packed(&vector["packed"]);
+
+        let verifying_key = VerifyingKey::deserialize(bytes).unwrap();
+        assert_eq!(verifying_key.pack(), packed);
+        assert_eq!(VerifyingKey::unpack(&packed).unwrap(), verifying_key);
+
+        let verifying_share = VerifyingShare::deserialize(bytes).unwrap();
+        assert_eq!(verifying_share.pack(), packed);
+        assert_eq!(VerifyingShare::unpack(&packed).unwrap(), verifying_share);
+
+        let commitment = NonceCommitment::deserialize(bytes).unwrap();
+        assert_eq!(commitment.pack(), packed);
+        assert_eq!(NonceCommitment::unpack(&packed).unwrap(), commitment);
+    }
+}
+
+#[test]
+fn check_unpack_rejects_invalid_encodings() {
+    let mut modulus = [0u8; 32];
+    modulus.copy_from_slice(&Fq::MODULUS.to_bytes_le());
+    assert_eq!(unpack_point(&modulus), None);
+
+    // The identity (0, 1) with the sign bit set.
+    let mut negative_zero = [0u8; 32];
+    negative_zero[0] = 1;
+    negative_zero[31] = 0x80;
+    assert_eq!(unpack_point(&negative_zero), None);
+
+    // (0, -1) is on the curve but has order 2.
+    let mut small_order = [0u8; 32];
+    small_order.copy_from_slice(&(-Fq::from(1u64)).into_bigint().to_bytes_le());
+    assert!(unpack_point(&small_order).is_some());
+    assert!(VerifyingKey::unpack(&small_order).is_err());
+
+    let signature = &CIRCOMLIB_PACK["signatures"][0];
+    let mut packed = packed::<64>(&signature["packed"]);
+    // S + r is still below p, so the sum can be computed in the base field.
+    let S = field(&signature["S"]) + Fq::from_bigint(Fr::MODULUS).unwrap();
+    packed[32..].copy_from_slice(&S.into_bigint().to_bytes_le());
+    assert_eq!(
+        Signature::unpack(&packed),
+        Err(frost_core::Error::MalformedSignature)
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_hex_serde_adapter() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[serde(crate = "self::serde")]
+    struct Message {
+        #[serde(with = "crate::pack::hex")]
+        verifying_key: VerifyingKey,
+        #[serde(with = "crate::pack::hex")]
+        signature: Signature,
+    }
+
+    let vector = &CIRCOMLIB_PACK["signatures"][0];
+    let json = format!(
+        r#"{{"verifying_key":{},"signature":{}}}"#,
+        CIRCOMLIB_PACK["points"][0]["packed"], vector["packed"]
+    );
+
+    let message: Message = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&message).unwrap(), json);
+
+    let odd_length = json.replacen(r#"","#, r#"0","#, 1);
+    assert!(serde_json::from_str::<Message>(&odd_length).is_err());
+}
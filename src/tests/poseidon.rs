@@ -0,0 +1,158 @@
+use ark_ec::CurveGroup;
+use ark_ed_on_bn254::Fq;
+use ark_ff::MontFp;
+use lazy_static::lazy_static;
+use rand::thread_rng;
+use serde_json::Value;
+
+use crate::poseidon::{challenge, poseidon};
+use crate::*;
+
+lazy_static! {
+    pub static ref ELEMENTS: Value =
+        serde_json::from_str(include_str!("../../tests/helpers/elements.json").trim()).unwrap();
+    pub static ref REPAIR_SHARE: Value =
+        serde_json::from_str(include_str!("../../tests/helpers/repair-share.json").trim()).unwrap();
+}
+
+#[test]
+fn check_poseidon_matches_circomlib() {
+    // poseidon([1, 2]) from the circomlib test suite.
+    const EXPECTED: Fq =
+        MontFp!("7853200120776062878684798364095072458815029376092732009249414926327459813530");
+
+    assert_eq!(poseidon(&[Fq::from(1u64), Fq::from(2u64)]), EXPECTED);
+}
+
+#[test]
+fn check_challenge_hashes_coordinates() {
+    let mut rng = thread_rng();
+    let msg = b"message to sign";
+
+    let R = BabyJubJubGroup::generator() * BabyJubJubScalarField::random(&mut rng);
+    let A = BabyJubJubGroup::generator() * BabyJubJubScalarField::random(&mut rng);
+
+    let mut preimage = Vec::new();
+    preimage.extend_from_slice(&BabyJubJubGroup::serialize(&R));
+    preimage.extend_from_slice(&BabyJubJubGroup::serialize(&A));
+    preimage.extend_from_slice(msg);
+
+    assert_eq!(
+        BabyJubJubPoseidon::H2(&preimage),
+        challenge(&R.into_affine(), &A.into_affine(), msg)
+    );
+    // Inputs that do not start with two points are hashed as plain bytes.
+    assert_ne!(
+        BabyJubJubPoseidon::H2(&preimage[1..]),
+        challenge(&R.into_affine(), &A.into_affine(), &msg[1..])
+    );
+}
+
+#[test]
+fn check_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::batch_verify::<BabyJubJubPoseidon, _>(rng);
+}
+
+#[test]
+fn check_bad_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::bad_batch_verify::<BabyJubJubPoseidon, _>(rng);
+}
+
+#[test]
+fn empty_batch_verify() {
+    let rng = thread_rng();
+
+    frost_core::tests::batch::empty_batch_verify::<BabyJubJubPoseidon, _>(rng);
+}
+
+#[test]
+fn check_serialization_of_coefficient_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::coefficient_commitment::check_serialization_of_coefficient_commitment::<
+        BabyJubJubPoseidon,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_create_coefficient_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::coefficient_commitment::check_create_coefficient_commitment::<
+        BabyJubJubPoseidon,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_create_coefficient_commitment_error() {
+    frost_core::tests::coefficient_commitment::check_create_coefficient_commitment_error::<
+        BabyJubJubPoseidon,
+    >(&ELEMENTS);
+}
+
+#[test]
+fn check_get_value_of_coefficient_commitment() {
+    let rng = thread_rng();
+
+    frost_core::tests::coefficient_commitment::check_get_value_of_coefficient_commitment::<
+        BabyJubJubPoseidon,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_serialize_vss_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_serialize_vss_commitment::<BabyJubJubPoseidon, _>(rng);
+}
+
+#[test]
+fn check_deserialize_vss_commitment() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_deserialize_vss_commitment::<BabyJubJubPoseidon, _>(
+        rng,
+    );
+}
+
+#[test]
+fn check_deserialize_vss_commitment_error() {
+    let rng = thread_rng();
+    frost_core::tests::vss_commitment::check_deserialize_vss_commitment_error::<
+        BabyJubJubPoseidon,
+        _,
+    >(rng, &ELEMENTS);
+}
+
+#[test]
+fn check_repair_share_step_1() {
+    let rng = thread_rng();
+
+    frost_core::tests::repairable::check_repair_share_step_1::<BabyJubJubPoseidon, _>(rng);
+}
+
+#[test]
+fn check_repair_share_step_2() {
+    frost_core::tests::repairable::check_repair_share_step_2::<BabyJubJubPoseidon>(&REPAIR_SHARE);
+}
+
+#[test]
+fn check_repair_share_step_3() {
+    let rng = thread_rng();
+    frost_core::tests::repairable::check_repair_share_step_3::<BabyJubJubPoseidon, _>(
+        rng,
+        &REPAIR_SHARE,
+    );
+}
+
+#[test]
+fn check_repair_share_step_1_fails_with_invalid_min_signers() {
+    let rng = thread_rng();
+    frost_core::tests::repairable::check_repair_share_step_1_fails_with_invalid_min_signers::<
+        BabyJubJubPoseidon,
+        _,
+    >(rng);
+}
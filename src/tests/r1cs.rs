@@ -0,0 +1,117 @@
+use ark_ed_on_bn254::{constraints::FqVar, Fq};
+use ark_ff::UniformRand;
+use ark_r1cs_std::prelude::*;
+use ark_relations::r1cs::ConstraintSystem;
+use frost_core::{Signature, SigningKey, VerifyingKey};
+use rand::thread_rng;
+
+use crate::poseidon::poseidon;
+use crate::r1cs::{self, CircuitCiphersuite, SignatureVar, VerifyingKeyVar};
+use crate::*;
+
+/// Returns whether the verification circuit is satisfied.
+fn check_circuit<C>(verifying_key: &VerifyingKey<C>, msg: &[u8], signature: &Signature<C>) -> bool
+where
+    C: CircuitCiphersuite<SignatureSerialization = [u8; 64]>,
+{
+    let cs = ConstraintSystem::<Fq>::new_ref();
+    let m = C::MessageVar::new_input(cs.clone(), || Ok(C::message(msg))).unwrap();
+    let verifying_key = VerifyingKeyVar::new_input(cs.clone(), || Ok(verifying_key)).unwrap();
+    let signature = SignatureVar::new_witness(cs.clone(), || Ok(signature)).unwrap();
+
+    verifying_key.enforce_verify(&m, &signature).unwrap();
+    cs.is_satisfied().unwrap()
+}
+
+/// Checks that the circuit accepts exactly the signatures accepted by
+/// [`VerifyingKey::verify`].
+fn check_verify_matches_native<C>()
+where
+    C: CircuitCiphersuite<SignatureSerialization = [u8; 64]>,
+{
+    let mut rng = thread_rng();
+    let msg = b"message to sign";
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let verifying_key = VerifyingKey::from(&signing_key);
+    let signature = signing_key.sign(&mut rng, msg);
+
+    let other_key = VerifyingKey::from(&SigningKey::<C>::new(&mut rng));
+    let other_signature = signing_key.sign(&mut rng, msg);
+
+    // z + 1
+    let mut bytes = signature.serialize();
+    let z = BabyJubJubScalarField::deserialize(&bytes[32..].try_into().unwrap()).unwrap();
+    bytes[32..].copy_from_slice(&BabyJubJubScalarField::serialize(&(z + Fr::from(1u64))));
+    let tweaked_z = Signature::deserialize(bytes).unwrap();
+
+    // R from another signature of the same message
+    let mut bytes = signature.serialize();
+    bytes[..32].copy_from_slice(&other_signature.serialize()[..32]);
+    let tweaked_R = Signature::deserialize(bytes).unwrap();
+
+    let cases = [
+        (&verifying_key, &msg[..], &signature),
+        (&verifying_key, &msg[..], &other_signature),
+        (&verifying_key, b"other message", &signature),
+        (&other_key, &msg[..], &signature),
+        (&verifying_key, &msg[..], &tweaked_z),
+        (&verifying_key, &msg[..], &tweaked_R),
+    ];
+    for (i, (key, msg, signature)) in cases.into_iter().enumerate() {
+        let valid = key.verify(msg, signature).is_ok();
+        assert_eq!(valid, i < 2);
+        assert_eq!(check_circuit(key, msg, signature), valid);
+    }
+}
+
+#[test]
+fn check_poseidon_gadget() {
+    let mut rng = thread_rng();
+    for n in 1..=5 {
+        let inputs: Vec<Fq> = (0..n).map(|_| Fq::rand(&mut rng)).collect();
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let vars: Vec<FqVar> = inputs
+            .iter()
+            .map(|input| FqVar::new_witness(cs.clone(), || Ok(*input)).unwrap())
+            .collect();
+        let hash = r1cs::poseidon(&vars).unwrap();
+
+        assert_eq!(hash.value().unwrap(), poseidon(&inputs));
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
+
+#[test]
+fn check_serialize_point_gadget() {
+    let mut rng = thread_rng();
+    // The generator has a positive x and its negation a negative one.
+    let generator = BabyJubJubGroup::generator();
+    let points = [generator, -generator, generator * Fr::rand(&mut rng)];
+    for point in points {
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let var = r1cs::PointVar::new_witness(cs.clone(), || Ok(point)).unwrap();
+        let bytes = r1cs::serialize_point(&var).unwrap();
+
+        assert_eq!(
+            bytes.value().unwrap(),
+            BabyJubJubGroup::serialize(&point).to_vec()
+        );
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
+
+#[test]
+fn check_verify_poseidon() {
+    check_verify_matches_native::<BabyJubJubPoseidon>();
+}
+
+#[test]
+fn check_verify_circomlib() {
+    check_verify_matches_native::<BabyJubJubCircomlib>();
+}
+
+#[test]
+fn check_verify_sha256() {
+    check_verify_matches_native::<BabyJubJubSha256>();
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+
+use frost_core::frost::keys::{self, KeyPackage};
+use frost_core::Error;
+use rand::thread_rng;
+
+use super::helpers::{generate, sign};
+use crate::refresh::{self, dkg, RefreshingShare};
+use crate::*;
+
+type C = BabyJubJubSha256;
+
+#[test]
+fn check_refresh_with_dealer() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+
+    let (refreshing_shares, new_pubkeys) =
+        refresh::compute_refreshing_shares(&pubkeys, 3, &mut rng).unwrap();
+    let new_key_packages: Vec<KeyPackage<C>> = key_packages
+        .values()
+        .map(|key_package| {
+            refresh::refresh_share(
+                refreshing_shares[key_package.identifier()].clone(),
+                key_package,
+            )
+            .unwrap()
+        })
+        .collect();
+
+    assert_eq!(new_pubkeys.group_public(), pubkeys.group_public());
+    for key_package in &new_key_packages {
+        let identifier = key_package.identifier();
+        assert_eq!(key_package.group_public(), pubkeys.group_public());
+        assert_eq!(
+            key_package.public(),
+            &new_pubkeys.signer_pubkeys()[identifier]
+        );
+        assert_ne!(key_package.public(), &pubkeys.signer_pubkeys()[identifier]);
+    }
+
+    let msg = b"message to sign";
+    let signature = sign(&new_key_packages[..3], &new_pubkeys, msg).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+    assert_eq!(
+        keys::reconstruct(&new_key_packages[2..]).unwrap(),
+        keys::reconstruct(&key_packages.values().cloned().collect::<Vec<_>>()[..3]).unwrap()
+    );
+
+    // Old and new shares do not combine.
+    let old: Vec<_> = key_packages.values().cloned().collect();
+    let mixed = [old[0].clone(), old[1].clone(), new_key_packages[2].clone()];
+    assert_ne!(
+        keys::reconstruct(&mixed).unwrap(),
+        keys::reconstruct(&old[..3]).unwrap()
+    );
+}
+
+#[test]
+fn check_refresh_share_fails_with_invalid_share() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(3, 2);
+    let (refreshing_shares, _) = refresh::compute_refreshing_shares(&pubkeys, 2, &mut rng).unwrap();
+    let mut key_packages = key_packages.values();
+    let (first, second) = (key_packages.next().unwrap(), key_packages.next().unwrap());
+
+    let share = &refreshing_shares[first.identifier()];
+    assert_eq!(
+        refresh::refresh_share(share.clone(), second),
+        Err(Error::UnknownIdentifier)
+    );
+
+    let tampered = RefreshingShare::new(
+        *share.identifier(),
+        *refreshing_shares[second.identifier()].value(),
+        share.commitment().clone(),
+    );
+    assert_eq!(
+        refresh::refresh_share(tampered, first),
+        Err(Error::InvalidSecretShare)
+    );
+
+    let (refreshing_shares, _) = refresh::compute_refreshing_shares(&pubkeys, 3, &mut rng).unwrap();
+    assert_eq!(
+        refresh::refresh_share(refreshing_shares[first.identifier()].clone(), first),
+        Err(Error::IncorrectNumberOfCommitments)
+    );
+
+    assert_eq!(
+        refresh::compute_refreshing_shares(&pubkeys, 4, &mut rng),
+        Err(Error::InvalidMinSigners)
+    );
+}
+
+#[test]
+fn check_refresh_with_dkg() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+
+    let mut round1_secret_packages = HashMap::new();
+    let mut round1_packages = HashMap::new();
+    for identifier in key_packages.keys() {
+        let (secret_package, package) = dkg::part1(*identifier, 5, 3, &mut rng).unwrap();
+        round1_secret_packages.insert(*identifier, secret_package);
+        round1_packages.insert(*identifier, package);
+    }
+
+    let mut round2_secret_packages = HashMap::new();
+    let mut received_round2_packages: HashMap<_, HashMap<_, _>> = HashMap::new();
+    for (identifier, secret_package) in round1_secret_packages {
+        let mut others = round1_packages.clone();
+        others.remove(&identifier);
+        let (secret_package, packages) = dkg::part2(secret_package, &others).unwrap();
+        round2_secret_packages.insert(identifier, secret_package);
+        for (receiver, package) in packages {
+            received_round2_packages
+                .entry(receiver)
+                .or_default()
+                .insert(identifier, package);
+        }
+    }
+
+    let mut new_key_packages = Vec::new();
+    let mut new_pubkeys = Vec::new();
+    for (identifier, key_package) in &key_packages {
+        let mut others = round1_packages.clone();
+        others.remove(identifier);
+        let (key_package, pubkeys) = dkg::part3(
+            &round2_secret_packages[identifier],
+            &others,
+            &received_round2_packages[identifier],
+            key_package,
+            &pubkeys,
+        )
+        .unwrap();
+        new_key_packages.push(key_package);
+        new_pubkeys.push(pubkeys);
+    }
+
+    assert!(new_pubkeys.iter().all(|p| *p == new_pubkeys[0]));
+    assert_eq!(new_pubkeys[0].group_public(), pubkeys.group_public());
+    assert_ne!(new_pubkeys[0], pubkeys);
+
+    let msg = b"message to sign";
+    let signature = sign(&new_key_packages[1..4], &new_pubkeys[0], msg).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+
+    // Old and new shares do not sign together.
+    let old: Vec<_> = key_packages.values().cloned().collect();
+    assert!(sign(
+        [&old[0], &old[1], &new_key_packages[2]],
+        &new_pubkeys[0],
+        msg
+    )
+    .is_err());
+
+    // A tampered round 2 package is detected.
+    let identifier = *new_key_packages[0].identifier();
+    let mut tampered = received_round2_packages[&identifier].clone();
+    let sender = *tampered.keys().next().unwrap();
+    tampered.insert(
+        sender,
+        dkg::round2::Package::new(keys::SigningShare::new(Fr::from(1u64))),
+    );
+    let mut others = round1_packages.clone();
+    others.remove(&identifier);
+    assert_eq!(
+        dkg::part3(
+            &round2_secret_packages[&identifier],
+            &others,
+            &tampered,
+            &key_packages[&identifier],
+            &pubkeys,
+        ),
+        Err(Error::InvalidSecretShare)
+    );
+
+    // Packages keyed with the participant's own identifier in place of
+    // another participant's.
+    let mut own_round1 = others.clone();
+    own_round1.remove(&sender);
+    own_round1.insert(identifier, round1_packages[&identifier].clone());
+    let mut own_round2 = received_round2_packages[&identifier].clone();
+    let package = own_round2.remove(&sender).unwrap();
+    own_round2.insert(identifier, package);
+    assert_eq!(
+        dkg::part3(
+            &round2_secret_packages[&identifier],
+            &own_round1,
+            &own_round2,
+            &key_packages[&identifier],
+            &pubkeys,
+        ),
+        Err(Error::DuplicatedIdentifier)
+    );
+}
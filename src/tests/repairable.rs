@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use frost_core::frost::keys::{self, KeyPackage, SigningShare};
+use frost_core::Error;
+use lazy_static::lazy_static;
+use rand::thread_rng;
+use serde_json::Value;
+
+use crate::repairable::{self, Delta, RepairError, Sigma};
+use crate::{BabyJubJubSha256, Fr};
+
+lazy_static! {
+    pub static ref REPAIR_SHARE: Value =
+        serde_json::from_str(include_str!("../../tests/helpers/repair-share.json").trim()).unwrap();
+}
+
+#[test]
+fn check_repair_share_step_1() {
+    let rng = thread_rng();
+
+    frost_core::tests::repairable::check_repair_share_step_1::<BabyJubJubSha256, _>(rng);
+}
+
+#[test]
+fn check_repair_share_step_2() {
+    frost_core::tests::repairable::check_repair_share_step_2::<BabyJubJubSha256>(&REPAIR_SHARE);
+}
+
+#[test]
+fn check_repair_share_step_3() {
+    let rng = thread_rng();
+    frost_core::tests::repairable::check_repair_share_step_3::<BabyJubJubSha256, _>(
+        rng,
+        &REPAIR_SHARE,
+    );
+}
+
+#[test]
+fn check_repair_share_step_1_fails_with_invalid_min_signers() {
+    let rng = thread_rng();
+    frost_core::tests::repairable::check_repair_share_step_1_fails_with_invalid_min_signers::<
+        BabyJubJubSha256,
+        _,
+    >(rng);
+}
+
+#[test]
+fn check_verifiable_repair_share() {
+    let mut rng = thread_rng();
+    let (shares, pubkeys) = keys::generate_with_dealer::<BabyJubJubSha256, _>(
+        5,
+        3,
+        keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: HashMap<_, _> = shares
+        .iter()
+        .map(|(id, share)| (*id, KeyPackage::try_from(share.clone()).unwrap()))
+        .collect();
+    let mut identifiers: Vec<_> = shares.keys().copied().collect();
+    identifiers.sort();
+    let participant = identifiers[0];
+    let helpers = &identifiers[1..4];
+    let commitment = shares[&participant].commitment();
+
+    let mut received: HashMap<_, Vec<Delta<_>>> = HashMap::new();
+    for helper in helpers {
+        let deltas =
+            repairable::repair_share_step_1(helpers, &key_packages[helper], &mut rng, participant)
+                .unwrap();
+        for (receiver, delta) in deltas {
+            received.entry(receiver).or_default().push(delta);
+        }
+    }
+    let sigmas: Vec<_> = helpers
+        .iter()
+        .map(|helper| repairable::repair_share_step_2(*helper, &received[helper]).unwrap())
+        .collect();
+
+    let share =
+        repairable::repair_share_step_3(&sigmas, participant, commitment, &pubkeys).unwrap();
+    assert_eq!(share, shares[&participant]);
+
+    // Messages round-trip through serde.
+    #[cfg(feature = "serde")]
+    {
+        let delta = &received[&helpers[0]][0];
+        let json = serde_json::to_string(delta).unwrap();
+        assert_eq!(&serde_json::from_str::<Delta<_>>(&json).unwrap(), delta);
+        let json = serde_json::to_string(&sigmas[0]).unwrap();
+        assert_eq!(serde_json::from_str::<Sigma<_>>(&json).unwrap(), sigmas[0]);
+    }
+
+    // A delta that does not match its commitment is blamed on its sender.
+    let mut deltas = received[&helpers[0]].clone();
+    let delta = &deltas[1];
+    deltas[1] = Delta::new(
+        *delta.helper(),
+        SigningShare::new(delta.value().to_scalar() + Fr::from(1u64)),
+        delta.commitments().clone(),
+    );
+    let culprit = *deltas[1].helper();
+    assert_eq!(
+        repairable::repair_share_step_2(helpers[0], &deltas),
+        Err(RepairError::InvalidDelta { culprit })
+    );
+
+    // The helper's own delta must be among the deltas.
+    let others: Vec<_> = received[&helpers[0]]
+        .iter()
+        .filter(|delta| *delta.helper() != helpers[0])
+        .cloned()
+        .collect();
+    assert_eq!(
+        repairable::repair_share_step_2(helpers[0], &others),
+        Err(RepairError::Frost(Error::UnknownIdentifier))
+    );
+    assert_eq!(
+        repairable::repair_share_step_2(identifiers[4], &received[&helpers[0]]),
+        Err(RepairError::Frost(Error::UnknownIdentifier))
+    );
+
+    // A wrong sigma is blamed on its helper.
+    let mut tampered = sigmas.clone();
+    tampered[2] = Sigma::new(
+        helpers[2],
+        SigningShare::new(sigmas[2].value().to_scalar() + Fr::from(1u64)),
+        sigmas[2].commitments().clone(),
+    );
+    let err = repairable::repair_share_step_3(&tampered, participant, commitment, &pubkeys);
+    assert_eq!(
+        err,
+        Err(RepairError::InvalidSigma {
+            culprit: helpers[2]
+        })
+    );
+    assert_eq!(err.unwrap_err().culprit(), Some(helpers[2]));
+
+    // A helper using another share is detected by its commitments.
+    let (other, _) = keys::generate_with_dealer::<BabyJubJubSha256, _>(
+        5,
+        3,
+        keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let other = KeyPackage::try_from(other[&helpers[1]].clone()).unwrap();
+    let mut received: HashMap<_, Vec<Delta<_>>> = HashMap::new();
+    for helper in helpers {
+        let key_package = if *helper == helpers[1] {
+            &other
+        } else {
+            &key_packages[helper]
+        };
+        let deltas =
+            repairable::repair_share_step_1(helpers, key_package, &mut rng, participant).unwrap();
+        for (receiver, delta) in deltas {
+            received.entry(receiver).or_default().push(delta);
+        }
+    }
+    let sigmas: Vec<_> = helpers
+        .iter()
+        .map(|helper| repairable::repair_share_step_2(*helper, &received[helper]).unwrap())
+        .collect();
+    assert_eq!(
+        repairable::repair_share_step_3(&sigmas, participant, commitment, &pubkeys),
+        Err(RepairError::InvalidDelta {
+            culprit: helpers[1]
+        })
+    );
+
+    assert_eq!(
+        repairable::repair_share_step_3(&sigmas[..2], participant, commitment, &pubkeys),
+        Err(RepairError::Frost(Error::IncorrectNumberOfIdentifiers))
+    );
+    assert_eq!(
+        repairable::repair_share_step_1(
+            &helpers[..2],
+            &key_packages[&helpers[0]],
+            &mut rng,
+            participant
+        ),
+        Err(Error::IncorrectNumberOfIdentifiers)
+    );
+}
@@ -0,0 +1,140 @@
+use std::collections::{BTreeSet, HashMap};
+
+use frost_core::frost::{
+    keys::{self, SecretShare, SigningShare},
+    Identifier,
+};
+use frost_core::Error;
+use rand::thread_rng;
+
+use super::helpers::{generate, sign};
+use crate::resharing;
+use crate::*;
+
+type C = BabyJubJubSha256;
+
+#[test]
+fn check_reshare_to_new_participants() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let dealers: BTreeSet<_> = key_packages.keys().copied().take(3).collect();
+    let new_identifiers: Vec<Identifier<C>> = (10..17u16).map(|i| i.try_into().unwrap()).collect();
+
+    let mut received: HashMap<_, HashMap<_, _>> = HashMap::new();
+    for dealer in &dealers {
+        let shares = resharing::part1(
+            &key_packages[dealer],
+            &dealers,
+            &new_identifiers,
+            4,
+            &mut rng,
+        )
+        .unwrap();
+        for (identifier, share) in shares {
+            received
+                .entry(identifier)
+                .or_default()
+                .insert(*dealer, share);
+        }
+    }
+
+    let mut new_key_packages = Vec::new();
+    let mut new_pubkeys = Vec::new();
+    for identifier in &new_identifiers {
+        let (key_package, pubkeys) = resharing::part2(
+            *identifier,
+            &new_identifiers,
+            4,
+            &received[identifier],
+            &pubkeys,
+        )
+        .unwrap();
+        assert_eq!(*key_package.min_signers(), 4);
+        new_key_packages.push(key_package);
+        new_pubkeys.push(pubkeys);
+    }
+    assert!(new_pubkeys.iter().all(|p| *p == new_pubkeys[0]));
+    assert_eq!(new_pubkeys[0].group_public(), pubkeys.group_public());
+    assert_eq!(new_pubkeys[0].signer_pubkeys().len(), 7);
+
+    let msg = b"message to sign";
+    let signature = sign(&new_key_packages[3..], &new_pubkeys[0], msg).unwrap();
+    assert!(pubkeys.group_public().verify(msg, &signature).is_ok());
+    assert!(sign(&new_key_packages[..3], &new_pubkeys[0], msg).is_err());
+    assert_eq!(
+        keys::reconstruct(&new_key_packages[..4]).unwrap(),
+        keys::reconstruct(&key_packages.values().cloned().collect::<Vec<_>>()).unwrap()
+    );
+
+    // A share that does not match its commitment is rejected.
+    let identifier = new_identifiers[0];
+    let mut shares = received[&identifier].clone();
+    let dealer = *dealers.first().unwrap();
+    let share = &shares[&dealer];
+    let tampered = SecretShare::new(
+        identifier,
+        SigningShare::new(share.value().to_scalar() + Fr::from(1u64)),
+        share.commitment().clone(),
+    );
+    shares.insert(dealer, tampered);
+    assert_eq!(
+        resharing::part2(identifier, &new_identifiers, 4, &shares, &pubkeys),
+        Err(Error::InvalidSecretShare)
+    );
+
+    // Too few dealers cannot reproduce the group key.
+    let mut shares = received[&identifier].clone();
+    shares.remove(&dealer);
+    assert_eq!(
+        resharing::part2(identifier, &new_identifiers, 4, &shares, &pubkeys),
+        Err(Error::IncorrectCommitment)
+    );
+}
+
+#[test]
+fn check_reshare_rejects_changed_secret() {
+    let mut rng = thread_rng();
+    let (key_packages, pubkeys) = generate::<C>(3, 2);
+    let dealers: BTreeSet<_> = key_packages.keys().copied().take(2).collect();
+    let new_identifiers: Vec<Identifier<C>> = (1..=3u16).map(|i| i.try_into().unwrap()).collect();
+
+    // A dealer sharing another secret with a valid commitment.
+    let dealer = *dealers.first().unwrap();
+    let other = &generate::<C>(3, 2).0[&dealer];
+    let mut received: HashMap<_, _> = HashMap::new();
+    for d in &dealers {
+        let key_package = if *d == dealer {
+            other
+        } else {
+            &key_packages[d]
+        };
+        let mut shares =
+            resharing::part1(key_package, &dealers, &new_identifiers, 2, &mut rng).unwrap();
+        received.insert(*d, shares.remove(&new_identifiers[0]).unwrap());
+    }
+    assert_eq!(
+        resharing::part2(new_identifiers[0], &new_identifiers, 2, &received, &pubkeys),
+        Err(Error::IncorrectCommitment)
+    );
+
+    assert_eq!(
+        resharing::part1(
+            &key_packages[&dealer],
+            &dealers,
+            &new_identifiers,
+            4,
+            &mut rng
+        ),
+        Err(Error::InvalidMinSigners)
+    );
+    assert_eq!(
+        resharing::part1(
+            &key_packages[&dealer],
+            &dealers.iter().copied().take(1).collect(),
+            &new_identifiers,
+            2,
+            &mut rng
+        ),
+        Err(Error::IncorrectNumberOfShares)
+    );
+}
@@ -0,0 +1,141 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use ark_ff::Field as _;
+use frost_core::{Signature, VerifyingKey};
+use revm::{
+    db::{CacheDB, EmptyDB},
+    primitives::{Address, Bytes, ExecutionResult, Output, TxKind},
+    Evm,
+};
+use serde_json::{json, Value};
+
+use super::helpers::{generate, sign};
+use crate::solidity::{abi_encode_verify_call, verifier_library};
+use crate::*;
+
+type C = BabyJubJubKeccak256;
+
+/// Compiles `source` with `solc`, or the compiler in `$SOLC`, through its
+/// standard JSON interface and returns the creation bytecode of the contract
+/// `name`.
+///
+/// The settings below are the ones the generated library is tested with:
+/// optimizer enabled with 200 runs, targeting the Cancun EVM.
+fn compile(source: &str, name: &str) -> Bytes {
+    let input = json!({
+        "language": "Solidity",
+        "sources": { "FrostVerifier.sol": { "content": source } },
+        "settings": {
+            "optimizer": { "enabled": true, "runs": 200 },
+            "evmVersion": "cancun",
+            "outputSelection": { "*": { "*": ["evm.bytecode.object"] } },
+        },
+    });
+    let solc = std::env::var("SOLC").unwrap_or_else(|_| "solc".to_owned());
+    let mut child = Command::new(&solc)
+        .arg("--standard-json")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap_or_else(|err| panic!("cannot run {solc}: {err}"));
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.to_string().as_bytes())
+        .unwrap();
+    let output: Value = serde_json::from_slice(&child.wait_with_output().unwrap().stdout).unwrap();
+    for error in output["errors"].as_array().into_iter().flatten() {
+        assert_ne!(error["severity"], "error", "{}", error["formattedMessage"]);
+    }
+    let object = output["contracts"]["FrostVerifier.sol"][name]["evm"]["bytecode"]["object"]
+        .as_str()
+        .unwrap();
+    hex::decode(object).unwrap().into()
+}
+
+/// Deploys the contract with creation bytecode `code` in `evm` and returns
+/// its address.
+fn deploy(evm: &mut Evm<'_, (), CacheDB<EmptyDB>>, code: Bytes) -> Address {
+    let tx = evm.tx_mut();
+    tx.transact_to = TxKind::Create;
+    tx.data = code;
+    tx.gas_limit = 30_000_000;
+    match evm.transact_commit().unwrap() {
+        ExecutionResult::Success {
+            output: Output::Create(_, Some(address)),
+            ..
+        } => address,
+        result => panic!("deployment failed: {result:?}"),
+    }
+}
+
+/// Calls the verifier at `verifier` with `calldata` and returns the decoded
+/// `bool`.
+fn call_verifier(
+    evm: &mut Evm<'_, (), CacheDB<EmptyDB>>,
+    verifier: Address,
+    calldata: Vec<u8>,
+) -> bool {
+    let tx = evm.tx_mut();
+    tx.transact_to = TxKind::Call(verifier);
+    tx.data = calldata.into();
+    match evm.transact().unwrap().result {
+        ExecutionResult::Success {
+            output: Output::Call(output),
+            ..
+        } => {
+            assert_eq!(output.len(), 32);
+            assert!(output[..31].iter().all(|byte| *byte == 0));
+            output[31] == 1
+        }
+        result => panic!("verify did not return: {result:?}"),
+    }
+}
+
+#[test]
+fn check_verifier_library_in_evm() {
+    let code = compile(&verifier_library::<C>("FrostVerifier"), "FrostVerifier");
+    let mut evm = Evm::builder()
+        .with_db(CacheDB::new(EmptyDB::default()))
+        .build();
+    let verifier = deploy(&mut evm, code);
+    let mut verify = |calldata| call_verifier(&mut evm, verifier, calldata);
+
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let verifying_key = pubkeys.group_public();
+    let msg = b"message to verify on chain";
+    let signature = sign(key_packages.values().take(3), &pubkeys, msg).unwrap();
+    assert!(verify(abi_encode_verify_call(
+        verifying_key,
+        msg,
+        &signature
+    )));
+    // An empty message takes no padding.
+    let empty = sign(key_packages.values().skip(2), &pubkeys, b"").unwrap();
+    assert!(verify(abi_encode_verify_call(verifying_key, b"", &empty)));
+
+    let bytes = signature.serialize();
+    let R = BabyJubJubGroup::deserialize(&bytes[..32].try_into().unwrap()).unwrap();
+    let z = BabyJubJubScalarField::deserialize(&bytes[32..].try_into().unwrap()).unwrap();
+    let modified_z = Signature::<C>::new(R, z + Fr::ONE);
+    assert!(!verify(abi_encode_verify_call(
+        verifying_key,
+        msg,
+        &modified_z
+    )));
+    let modified_R = Signature::<C>::new(R + BabyJubJubGroup::generator(), z);
+    assert!(!verify(abi_encode_verify_call(
+        verifying_key,
+        msg,
+        &modified_R
+    )));
+    assert!(!verify(abi_encode_verify_call(
+        verifying_key,
+        b"another message",
+        &signature
+    )));
+    let other_key = VerifyingKey::<C>::new(BabyJubJubGroup::generator());
+    assert!(!verify(abi_encode_verify_call(&other_key, msg, &signature)));
+}
@@ -0,0 +1,110 @@
+use std::str::FromStr;
+
+use ark_ec::{twisted_edwards::TECurveConfig, CurveGroup};
+use ark_ff::{BigInteger, Field as _, PrimeField};
+use rand::thread_rng;
+
+use crate::babyjubjub::{EdwardsConfig, EdwardsProjective};
+use crate::keccak256::hash_to_array;
+use crate::poseidon::decode_point;
+use crate::solidity::{
+    abi_encode_signature, abi_encode_verify_call, abi_encode_verifying_key, verifier_library,
+};
+use crate::*;
+
+type C = BabyJubJubKeccak256;
+
+/// Returns the value of the constant `name` declared in `source`.
+fn constant(source: &str, name: &str) -> String {
+    let declaration = format!("uint256 internal constant {name} = ");
+    let start = source.find(&declaration).unwrap() + declaration.len();
+    let end = start + source[start..].find(';').unwrap();
+    source[start..end].to_owned()
+}
+
+fn decimal(value: impl PrimeField) -> String {
+    value.into_bigint().to_string()
+}
+
+#[test]
+fn check_verifier_library_constants() {
+    let source = verifier_library::<C>("FrostVerifier");
+    let generator = BabyJubJubGroup::generator();
+
+    assert!(source.contains("library FrostVerifier {"));
+    // No `{NAME}`-style placeholder is left.
+    let placeholder = source.split('{').skip(1).find(|rest| {
+        rest.find('}').is_some_and(|end| {
+            end > 0
+                && rest[..end]
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+        })
+    });
+    assert_eq!(placeholder, None);
+
+    assert_eq!(constant(&source, "P"), Fq::MODULUS.to_string());
+    assert_eq!(constant(&source, "R_ORDER"), Fr::MODULUS.to_string());
+    assert_eq!(
+        constant(&source, "COEFF_A"),
+        decimal(EdwardsConfig::COEFF_A)
+    );
+    assert_eq!(
+        constant(&source, "COEFF_D"),
+        decimal(EdwardsConfig::COEFF_D)
+    );
+    assert_eq!(constant(&source, "GX"), decimal(generator.into_affine().x));
+    assert_eq!(constant(&source, "GY"), decimal(generator.into_affine().y));
+
+    // ROOT is a primitive 2^S-th root of unity for Tonelli-Shanks.
+    let s: u32 = constant(&source, "S").parse().unwrap();
+    let root = Fq::from_str(&constant(&source, "ROOT")).unwrap();
+    assert_eq!(root.pow([1u64 << s]), Fq::ONE);
+    assert_ne!(root.pow([1u64 << (s - 1)]), Fq::ONE);
+
+    assert!(source.contains("\"FROST-babyjubjub-KECCAK256-v1chal\""));
+    assert!(source.contains(&decimal(Fr::from(2u64).pow([256]))));
+}
+
+#[test]
+fn check_abi_encoding_of_aggregate_signature() {
+    let (msg, signature, verifying_key) =
+        frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<C, _>(thread_rng());
+
+    let A = abi_encode_verifying_key(&verifying_key);
+    let encoded = abi_encode_signature(&signature);
+    let (R, z) = encoded.split_at(32);
+    assert_eq!(A, verifying_key.serialize());
+    assert_eq!(R, &signature.serialize()[..32]);
+    let z = Fr::from_be_bytes_mod_order(z);
+    assert_eq!(z.into_bigint().to_bytes_be(), encoded[32..]);
+
+    // The challenge computed like the generated `challenge` function makes the
+    // verification equation hold.
+    let preimage = [b"FROST-babyjubjub-KECCAK256-v1chal", R, &A[..], &msg[..]].concat();
+    let h0 = Fr::from_be_bytes_mod_order(&hash_to_array(&[&preimage, &[0]]));
+    let h1 = Fr::from_be_bytes_mod_order(&hash_to_array(&[&preimage, &[1]]));
+    let c = h0 * Fr::from(2u64).pow([256]) + h1;
+    let A: EdwardsProjective = decode_point(&A).unwrap().into();
+    let R: EdwardsProjective = decode_point(R).unwrap().into();
+    assert_eq!(BabyJubJubGroup::generator() * z, R + A * c);
+
+    let calldata = abi_encode_verify_call(&verifying_key, &msg, &signature);
+    assert_eq!(
+        calldata[..4],
+        hash_to_array(&[b"verify(bytes32,bytes32,uint256,bytes)"])[..4]
+    );
+    assert_eq!(calldata[4..36], abi_encode_verifying_key(&verifying_key));
+    assert_eq!(calldata[36..100], encoded);
+    assert_eq!(
+        calldata[100..132],
+        Fr::from(128u64).into_bigint().to_bytes_be()
+    );
+    assert_eq!(
+        calldata[132..164],
+        Fr::from(msg.len() as u64).into_bigint().to_bytes_be()
+    );
+    assert_eq!(calldata[164..164 + msg.len()], msg);
+    assert_eq!(calldata.len(), 164 + msg.len().div_ceil(32) * 32);
+    assert!(calldata[164 + msg.len()..].iter().all(|byte| *byte == 0));
+}
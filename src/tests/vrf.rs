@@ -0,0 +1,209 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use frost_core::{
+    frost::{
+        self,
+        keys::{KeyPackage, PublicKeyPackage},
+        Identifier,
+    },
+    Error, Group, SigningKey, VerifyingKey,
+};
+use rand::thread_rng;
+
+use super::helpers::generate;
+use crate::dleq::CombineError;
+use crate::vrf::{self, round1, round2};
+use crate::*;
+
+type C = BabyJubJubSha256;
+
+/// Runs both rounds of the threshold VRF with `key_packages`.
+#[allow(clippy::type_complexity)]
+fn evaluate<'a>(
+    key_packages: impl IntoIterator<Item = &'a KeyPackage<C>>,
+    input: &[u8],
+) -> (
+    BTreeMap<Identifier<C>, round1::Commitments<C>>,
+    HashMap<Identifier<C>, round2::Response<C>>,
+) {
+    let mut rng = thread_rng();
+    let key_packages: Vec<_> = key_packages.into_iter().collect();
+    let mut nonces = HashMap::new();
+    let mut commitments = BTreeMap::new();
+    for key_package in &key_packages {
+        let (nonce, commitment) = round1::commit(key_package, input, &mut rng).unwrap();
+        nonces.insert(*key_package.identifier(), nonce);
+        commitments.insert(*key_package.identifier(), commitment);
+    }
+    let responses = key_packages
+        .iter()
+        .map(|key_package| {
+            let identifier = key_package.identifier();
+            let response =
+                round2::respond(key_package, &nonces[identifier], input, &commitments).unwrap();
+            (*identifier, response)
+        })
+        .collect();
+    (commitments, responses)
+}
+
+fn threshold_proof(
+    key_packages: &BTreeMap<Identifier<C>, KeyPackage<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+    ids: &[Identifier<C>],
+    input: &[u8],
+) -> vrf::Proof<C> {
+    let (commitments, responses) = evaluate(ids.iter().map(|id| &key_packages[id]), input);
+    vrf::combine(input, &commitments, &responses, pubkeys, 3).unwrap()
+}
+
+#[test]
+fn check_prove_and_verify() {
+    let mut rng = thread_rng();
+    let signing_key = SigningKey::<C>::new(&mut rng);
+    let verifying_key = VerifyingKey::from(signing_key);
+    let proof = vrf::prove(&signing_key, b"input", &mut rng).unwrap();
+    let output = vrf::verify(&verifying_key, b"input", &proof).unwrap();
+    assert_eq!(output, proof.output());
+
+    // The output only depends on the key and the input.
+    let again = vrf::prove(&signing_key, b"input", &mut rng).unwrap();
+    assert_ne!(again, proof);
+    assert_eq!(vrf::verify(&verifying_key, b"input", &again), Ok(output));
+    let other = vrf::prove(&signing_key, b"other input", &mut rng).unwrap();
+    assert_ne!(
+        vrf::verify(&verifying_key, b"other input", &other).unwrap(),
+        output
+    );
+
+    assert_eq!(
+        vrf::verify(&verifying_key, b"other input", &proof),
+        Err(Error::InvalidSignature)
+    );
+    let other_key = VerifyingKey::from(SigningKey::<C>::new(&mut rng));
+    assert_eq!(
+        vrf::verify(&other_key, b"input", &proof),
+        Err(Error::InvalidSignature)
+    );
+    let forged = vrf::Proof::new(
+        *proof.gamma() + BabyJubJubGroup::generator(),
+        *proof.proof(),
+    );
+    assert_eq!(
+        vrf::verify(&verifying_key, b"input", &forged),
+        Err(Error::InvalidSignature)
+    );
+}
+
+#[test]
+fn check_hash_to_curve() {
+    let mut rng = thread_rng();
+    let verifying_key = VerifyingKey::from(SigningKey::<C>::new(&mut rng));
+    let point = vrf::hash_to_curve(&verifying_key, b"input").unwrap();
+    assert_ne!(point, BabyJubJubGroup::identity());
+    assert_eq!(
+        BabyJubJubGroup::deserialize(&BabyJubJubGroup::serialize(&point)).unwrap(),
+        point
+    );
+    assert_eq!(vrf::hash_to_curve(&verifying_key, b"input"), Ok(point));
+    assert_ne!(vrf::hash_to_curve(&verifying_key, b"other"), Ok(point));
+}
+
+#[test]
+fn check_threshold_vrf() {
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let ids: Vec<_> = key_packages.keys().copied().collect();
+    let proof = threshold_proof(&key_packages, &pubkeys, &ids[..3], b"round 1");
+    let output = vrf::verify(pubkeys.group_public(), b"round 1", &proof).unwrap();
+
+    // Every subset of signers gets the output of the whole signing key.
+    let other = threshold_proof(&key_packages, &pubkeys, &ids[1..], b"round 1");
+    assert_eq!(
+        vrf::verify(pubkeys.group_public(), b"round 1", &other),
+        Ok(output)
+    );
+    let key_packages_vec: Vec<_> = key_packages.values().cloned().collect();
+    let signing_key = frost::keys::reconstruct(&key_packages_vec).unwrap();
+    let single = vrf::prove(&signing_key, b"round 1", &mut thread_rng()).unwrap();
+    assert_eq!(single.gamma(), proof.gamma());
+    assert_eq!(single.output(), output);
+
+    let next = threshold_proof(&key_packages, &pubkeys, &ids[..3], b"round 2");
+    assert_ne!(
+        vrf::verify(pubkeys.group_public(), b"round 2", &next).unwrap(),
+        output
+    );
+
+    #[cfg(feature = "serde")]
+    {
+        let json = serde_json::to_string(&proof).unwrap();
+        assert_eq!(serde_json::from_str::<vrf::Proof<C>>(&json).unwrap(), proof);
+        let (commitments, responses) = evaluate(key_packages.values().take(3), b"round 1");
+        let json = serde_json::to_string(&commitments[&ids[0]]).unwrap();
+        assert_eq!(
+            serde_json::from_str::<round1::Commitments<C>>(&json).unwrap(),
+            commitments[&ids[0]]
+        );
+        let json = serde_json::to_string(&responses[&ids[0]]).unwrap();
+        assert_eq!(
+            serde_json::from_str::<round2::Response<C>>(&json).unwrap(),
+            responses[&ids[0]]
+        );
+    }
+}
+
+#[test]
+fn check_combine_names_invalid_responses() {
+    let (key_packages, pubkeys) = generate::<C>(5, 3);
+    let ids: Vec<_> = key_packages.keys().copied().collect();
+    let (commitments, mut responses) = evaluate(key_packages.values().take(4), b"input");
+
+    // A wrong response, a missing response and a response from a
+    // participant without commitments.
+    let response = *responses[&ids[0]].response();
+    responses.insert(ids[0], round2::Response::new(response + response));
+    responses.remove(&ids[1]);
+    responses.insert(ids[4], responses[&ids[2]]);
+    let err = vrf::combine(b"input", &commitments, &responses, &pubkeys, 3).unwrap_err();
+    assert_eq!(
+        err,
+        CombineError::InvalidShares(BTreeSet::from([ids[0], ids[1], ids[4]]))
+    );
+    assert_eq!(err.culprits(), vec![ids[0], ids[1], ids[4]]);
+
+    // Too few participants.
+    let (mut commitments, responses) = evaluate(key_packages.values().take(3), b"input");
+    commitments.remove(&ids[2]);
+    assert_eq!(
+        vrf::combine(b"input", &commitments, &responses, &pubkeys, 3),
+        Err(CombineError::Frost(Error::IncorrectNumberOfCommitments))
+    );
+}
+
+#[test]
+fn check_respond_checks_commitments() {
+    let mut rng = thread_rng();
+    let (key_packages, _) = generate::<C>(3, 2);
+    let ids: Vec<_> = key_packages.keys().copied().collect();
+    let key_package = &key_packages[&ids[0]];
+    let (nonces, own) = round1::commit(key_package, b"input", &mut rng).unwrap();
+    let (_, other) = round1::commit(&key_packages[&ids[1]], b"input", &mut rng).unwrap();
+
+    let commitments = BTreeMap::from([(ids[1], other)]);
+    assert_eq!(
+        round2::respond(key_package, &nonces, b"input", &commitments),
+        Err(Error::IncorrectNumberOfCommitments)
+    );
+    let commitments = BTreeMap::from([(ids[1], other), (ids[2], other)]);
+    assert_eq!(
+        round2::respond(key_package, &nonces, b"input", &commitments),
+        Err(Error::MissingCommitment)
+    );
+    // Commitments for another input.
+    let commitments = BTreeMap::from([(ids[0], own), (ids[1], other)]);
+    assert_eq!(
+        round2::respond(key_package, &nonces, b"other input", &commitments),
+        Err(Error::IncorrectCommitment)
+    );
+    assert!(round2::respond(key_package, &nonces, b"input", &commitments).is_ok());
+}
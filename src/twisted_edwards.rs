@@ -0,0 +1,153 @@
+//! FROST [`Field`] and [`Group`] implementations for any arkworks twisted
+//! Edwards curve.
+//!
+//! Scalars are encoded as 32 little-endian bytes and points with the arkworks
+//! compressed encoding, which must also fit in 32 bytes. This holds for all
+//! the usual SNARK-friendly curves (BabyJubJub, Jubjub, Bandersnatch,
+//! ed-on-bls12-377).
+//!
+//! The group is the prime-order subgroup generated by
+//! [`TECurveConfig::GENERATOR`]. Deserialization rejects points outside of
+//! it, so the cofactor never needs to be cleared by the caller.
+
+use core::marker::PhantomData;
+
+use ark_ec::{
+    twisted_edwards::{Affine, Projective, TECurveConfig},
+    CurveConfig, CurveGroup,
+};
+use ark_ff::{BigInteger, Field as ArkField, One, PrimeField, UniformRand, Zero};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Compress, Validate};
+use ark_std::vec::Vec;
+use rand_core::{CryptoRng, RngCore};
+
+use crate::{Field, FieldError, Group, GroupError};
+
+/// The scalar field of the prime-order subgroup of the twisted Edwards curve
+/// `P`.
+pub struct TEScalarField<P>(PhantomData<P>);
+
+impl<P> Clone for TEScalarField<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for TEScalarField<P> {}
+
+impl<P: TECurveConfig> Field for TEScalarField<P> {
+    type Scalar = P::ScalarField;
+
+    type Serialization = [u8; 32];
+
+    fn zero() -> Self::Scalar {
+        P::ScalarField::zero()
+    }
+
+    fn one() -> Self::Scalar {
+        P::ScalarField::one()
+    }
+
+    fn invert(scalar: &Self::Scalar) -> Result<Self::Scalar, FieldError> {
+        // [`Scalar`]'s Eq/PartialEq does a constant-time comparison
+        if *scalar == <Self as Field>::zero() {
+            Err(FieldError::InvalidZeroScalar)
+        } else {
+            Ok(scalar.inverse().unwrap())
+        }
+    }
+
+    fn random<R: RngCore + CryptoRng>(rng: &mut R) -> Self::Scalar {
+        P::ScalarField::rand(rng)
+    }
+
+    fn serialize(scalar: &Self::Scalar) -> Self::Serialization {
+        let bytes = scalar.into_bigint().to_bytes_le();
+        let mut array = [0u8; 32];
+        array[..bytes.len()].copy_from_slice(&bytes);
+        array
+    }
+
+    fn deserialize(buf: &Self::Serialization) -> Result<Self::Scalar, FieldError> {
+        Ok(P::ScalarField::from_le_bytes_mod_order(buf))
+    }
+
+    fn little_endian_serialize(scalar: &Self::Scalar) -> Self::Serialization {
+        Self::serialize(scalar)
+    }
+}
+
+/// The prime-order subgroup of the twisted Edwards curve `P`.
+pub struct TEGroup<P>(PhantomData<P>);
+
+impl<P> Clone for TEGroup<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for TEGroup<P> {}
+
+impl<P> PartialEq for TEGroup<P> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<P> Eq for TEGroup<P> {}
+
+impl<P: TECurveConfig> Group for TEGroup<P> {
+    type Field = TEScalarField<P>;
+
+    type Element = Projective<P>;
+
+    type Serialization = [u8; 32];
+
+    fn cofactor() -> <Self::Field as Field>::Scalar {
+        P::ScalarField::from_le_bytes_mod_order(
+            &<P as CurveConfig>::COFACTOR
+                .iter()
+                .flat_map(|limb| limb.to_le_bytes())
+                .collect::<Vec<u8>>(),
+        )
+    }
+
+    fn identity() -> Self::Element {
+        Projective::<P>::zero()
+    }
+
+    fn generator() -> Self::Element {
+        Projective::<P>::from(P::GENERATOR)
+    }
+
+    fn serialize(element: &Self::Element) -> Self::Serialization {
+        let mut vec = Vec::new();
+        let mut array = [0u8; 32];
+
+        let affine: Affine<P> = element.into_affine();
+
+        affine
+            .serialize_with_mode(&mut vec, Compress::Yes)
+            .expect("Serialization should succeed");
+
+        match vec.len() {
+            32 => array.copy_from_slice(&vec),
+            _ => panic!("Unexpected serialized length: {}", vec.len()),
+        }
+
+        array
+    }
+
+    fn deserialize(buf: &Self::Serialization) -> Result<Self::Element, GroupError> {
+        // Validation checks that the point is on the curve and in the
+        // prime-order subgroup.
+        let point = Projective::<P>::deserialize_with_mode(&buf[..], Compress::Yes, Validate::Yes)
+            .map_err(|_| GroupError::MalformedElement)?;
+
+        if point.is_zero() {
+            Err(GroupError::InvalidIdentityElement)
+        } else {
+            Ok(point)
+        }
+    }
+}
@@ -0,0 +1,459 @@
+//! A threshold verifiable random function in the style of ECVRF.
+//!
+//! The VRF of the input `alpha` under the key `x`, with `Y = x·G`, is defined
+//! as in ECVRF (RFC 9381):
+//!
+//! - `H = hash_to_curve(Y, alpha)`, with the try-and-increment method: the
+//!   first of `hash_to_array(ser(Y) || alpha || ctr)` for `ctr = 0, 1, ...`
+//!   that decodes to a point, multiplied by the cofactor;
+//! - `Gamma = x·H`, and the [`Proof`] is `Gamma` with a Chaum–Pedersen proof
+//!   that `log_G(Y) = log_H(Gamma)`;
+//! - the output is `hash_to_array(ser(Gamma))`.
+//!
+//! Every hash uses [`BabyJubJubHash`] with the domain
+//! `CONTEXT_STRING || label`, where the label is `vrf-h2c`, `dleq-vrf`,
+//! `vrf-rho` and `vrf-out`. [`prove`] computes a proof with a whole signing
+//! key, and [`verify`] checks it against a verifying key and returns the
+//! output.
+//!
+//! A threshold of the holders of the [`KeyPackage`]s of a group computes the
+//! same proof for the group [`VerifyingKey`] in two rounds, like FROST
+//! signing:
+//!
+//! - each participant calls [`round1::commit`], keeps the
+//!   [`round1::Nonces`] and sends the [`round1::Commitments`], which hold
+//!   its partial evaluation `Gamma_i = s_i·H` and its nonce commitments, to
+//!   the combiner;
+//! - the combiner sends all the commitments to each participant, which
+//!   calls [`round2::respond`] and returns its [`round2::Response`];
+//! - the combiner calls [`combine`], which checks each response against the
+//!   participant's verifying share, interpolates `Gamma` and sums the
+//!   responses into the [`Proof`].
+//!
+//! The nonces are bound to the set of commitments with binding factors, as
+//! in FROST, so that they can be used concurrently, but they must never be
+//! reused. [`combine`] names every participant whose partial evaluation or
+//! response is invalid in a [`CombineError`].
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use ark_ec::AffineRepr;
+use ark_serialize::{CanonicalDeserialize, Compress, Validate};
+use frost_core::{
+    frost::{
+        compute_lagrange_coefficient,
+        keys::{KeyPackage, PublicKeyPackage},
+        Identifier,
+    },
+    Ciphersuite, Element, Error, Field, Group, GroupError, Scalar, SigningKey, VerifyingKey,
+};
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "serde")]
+use frost_core::serde;
+
+use crate::babyjubjub::EdwardsAffine;
+use crate::dleq::{self, CombineError};
+use crate::{BabyJubJubGroup, BabyJubJubHash};
+
+/// The label of the proofs.
+const LABEL: &str = "vrf";
+
+/// Hashes `input` to a point of the prime-order subgroup, for the
+/// `verifying_key`.
+///
+/// Like the try-and-increment method of RFC 9381, it fails if none of the
+/// 256 counters gives a point, with [`GroupError::MalformedElement`]. Each
+/// attempt succeeds with probability about 1/2, so this does not happen in
+/// practice.
+pub fn hash_to_curve<C>(
+    verifying_key: &VerifyingKey<C>,
+    input: &[u8],
+) -> Result<Element<C>, Error<C>>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+{
+    let domain = C::CONTEXT_STRING.to_owned() + "vrf-h2c";
+    let mut msg = verifying_key.serialize().to_vec();
+    msg.extend_from_slice(input);
+    for ctr in 0u8..=u8::MAX {
+        msg.push(ctr);
+        let bytes = C::hash_to_array(domain.as_bytes(), &msg);
+        msg.pop();
+        let Ok(point) =
+            EdwardsAffine::deserialize_with_mode(&bytes[..], Compress::Yes, Validate::No)
+        else {
+            continue;
+        };
+        let point = point.mul_by_cofactor_to_group();
+        if point != BabyJubJubGroup::identity() {
+            return Ok(point);
+        }
+    }
+    Err(GroupError::MalformedElement.into())
+}
+
+/// A VRF proof, from which the output is computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound = "C: Ciphersuite<Group = BabyJubJubGroup>")
+)]
+#[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Proof<C: Ciphersuite<Group = BabyJubJubGroup>> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialization::element"))]
+    gamma: Element<C>,
+    proof: dleq::Proof<C>,
+}
+
+impl<C: Ciphersuite<Group = BabyJubJubGroup>> Proof<C> {
+    /// Creates a new [`Proof`].
+    pub fn new(gamma: Element<C>, proof: dleq::Proof<C>) -> Self {
+        Self { gamma, proof }
+    }
+
+    /// The evaluation `Gamma = x·H`.
+    pub fn gamma(&self) -> &Element<C> {
+        &self.gamma
+    }
+
+    /// The proof that `Gamma` matches the verifying key.
+    pub fn proof(&self) -> &dleq::Proof<C> {
+        &self.proof
+    }
+}
+
+impl<C> Proof<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+{
+    /// The VRF output. It must only be used once the proof is verified,
+    /// e.g. as returned by [`verify`].
+    pub fn output(&self) -> [u8; 32] {
+        C::hash_to_array(
+            (C::CONTEXT_STRING.to_owned() + "vrf-out").as_bytes(),
+            &BabyJubJubGroup::serialize(&self.gamma),
+        )
+    }
+}
+
+/// Computes the VRF proof of `input` with the whole `signing_key`.
+///
+/// It only fails if [`hash_to_curve`] does.
+pub fn prove<C, R>(
+    signing_key: &SigningKey<C>,
+    input: &[u8],
+    rng: &mut R,
+) -> Result<Proof<C>, Error<C>>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+    R: RngCore + CryptoRng,
+{
+    let H = hash_to_curve(&VerifyingKey::from(signing_key), input)?;
+    let x = signing_key.to_scalar();
+    Ok(Proof::new(H * x, dleq::prove(LABEL, x, &H, rng)))
+}
+
+/// Verifies the VRF `proof` of `input` for `verifying_key`, and returns the
+/// VRF output.
+pub fn verify<C>(
+    verifying_key: &VerifyingKey<C>,
+    input: &[u8],
+    proof: &Proof<C>,
+) -> Result<[u8; 32], Error<C>>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+{
+    let H = hash_to_curve(verifying_key, input)?;
+    if !dleq::verify(
+        LABEL,
+        &verifying_key.to_element(),
+        &H,
+        &proof.gamma,
+        &proof.proof,
+    ) {
+        return Err(Error::InvalidSignature);
+    }
+    Ok(proof.output())
+}
+
+/// Threshold VRF round 1 structures.
+pub mod round1 {
+    use super::*;
+
+    /// The nonces that must be kept in memory by the participant between
+    /// [`commit`] and [`round2::respond`].
+    ///
+    /// # Security
+    ///
+    /// The nonces MUST NOT be sent to other participants, nor used twice.
+    #[derive(Clone, PartialEq, Eq)]
+    pub struct Nonces<C: Ciphersuite> {
+        pub(super) hiding: Scalar<C>,
+        pub(super) binding: Scalar<C>,
+    }
+
+    impl<C: Ciphersuite> core::fmt::Debug for Nonces<C> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.debug_struct("Nonces")
+                .field("hiding", &"<redacted>")
+                .field("binding", &"<redacted>")
+                .finish()
+        }
+    }
+
+    /// The partial evaluation and nonce commitments that each participant
+    /// sends to the combiner in round 1.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound = "C: Ciphersuite<Group = BabyJubJubGroup>")
+    )]
+    #[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+    #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+    pub struct Commitments<C: Ciphersuite<Group = BabyJubJubGroup>> {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::element"))]
+        pub(super) gamma: Element<C>,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::element"))]
+        pub(super) hiding: Element<C>,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::element"))]
+        pub(super) binding: Element<C>,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::element"))]
+        pub(super) hiding_h: Element<C>,
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::element"))]
+        pub(super) binding_h: Element<C>,
+    }
+
+    impl<C: Ciphersuite<Group = BabyJubJubGroup>> Commitments<C> {
+        /// The partial evaluation `Gamma_i = s_i·H`.
+        pub fn gamma(&self) -> &Element<C> {
+            &self.gamma
+        }
+    }
+
+    /// Computes the partial evaluation of `input` of the participant holding
+    /// `key_package`, and generates its nonces for the proof.
+    ///
+    /// It returns the [`Nonces`] to keep for
+    /// [`round2::respond`] and the [`Commitments`] to
+    /// send to the combiner, and only fails if [`hash_to_curve`] does.
+    pub fn commit<C, R>(
+        key_package: &KeyPackage<C>,
+        input: &[u8],
+        rng: &mut R,
+    ) -> Result<(Nonces<C>, Commitments<C>), Error<C>>
+    where
+        C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+        R: RngCore + CryptoRng,
+    {
+        let H = hash_to_curve(key_package.group_public(), input)?;
+        let nonces = Nonces {
+            hiding: <<C::Group as Group>::Field>::random(rng),
+            binding: <<C::Group as Group>::Field>::random(rng),
+        };
+        let G = BabyJubJubGroup::generator();
+        let commitments = Commitments {
+            gamma: H * key_package.secret_share().to_scalar(),
+            hiding: G * nonces.hiding,
+            binding: G * nonces.binding,
+            hiding_h: H * nonces.hiding,
+            binding_h: H * nonces.binding,
+        };
+        Ok((nonces, commitments))
+    }
+}
+
+/// Threshold VRF round 2 structures.
+pub mod round2 {
+    use super::*;
+
+    /// A participant's share of the proof response, sent to the combiner in
+    /// round 2.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound = "C: Ciphersuite<Group = BabyJubJubGroup>")
+    )]
+    #[cfg_attr(feature = "serde", serde(crate = "self::serde"))]
+    #[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+    pub struct Response<C: Ciphersuite<Group = BabyJubJubGroup>> {
+        #[cfg_attr(feature = "serde", serde(with = "crate::serialization::scalar"))]
+        pub(super) response: Scalar<C>,
+    }
+
+    impl<C: Ciphersuite<Group = BabyJubJubGroup>> Response<C> {
+        /// Creates a new [`Response`].
+        pub fn new(response: Scalar<C>) -> Self {
+            Self { response }
+        }
+
+        /// The response `z_i = d_i + rho_i·e_i + c·lambda_i·s_i`.
+        pub fn response(&self) -> &Scalar<C> {
+            &self.response
+        }
+    }
+
+    /// Computes the [`Response`] of the participant holding `key_package`,
+    /// given the [`round1::Commitments`] of all
+    /// the participants, including its own.
+    pub fn respond<C>(
+        key_package: &KeyPackage<C>,
+        nonces: &round1::Nonces<C>,
+        input: &[u8],
+        commitments: &BTreeMap<Identifier<C>, round1::Commitments<C>>,
+    ) -> Result<Response<C>, Error<C>>
+    where
+        C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+    {
+        if commitments.len() < *key_package.min_signers() as usize {
+            return Err(Error::IncorrectNumberOfCommitments);
+        }
+        let identifier = key_package.identifier();
+        let own = commitments
+            .get(identifier)
+            .ok_or(Error::MissingCommitment)?;
+        let H = hash_to_curve(key_package.group_public(), input)?;
+        let secret = key_package.secret_share().to_scalar();
+        if own.gamma != H * secret
+            || own.hiding != BabyJubJubGroup::generator() * nonces.hiding
+            || own.binding != BabyJubJubGroup::generator() * nonces.binding
+        {
+            return Err(Error::IncorrectCommitment);
+        }
+
+        let transcript = Transcript::new(key_package.group_public(), &H, commitments)?;
+        let (rho, lambda) = transcript.factors[identifier];
+        Ok(Response::new(
+            nonces.hiding + rho * nonces.binding + transcript.challenge * lambda * secret,
+        ))
+    }
+}
+
+/// The values derived from the commitments, shared by the participants and
+/// the combiner.
+struct Transcript<C: Ciphersuite<Group = BabyJubJubGroup>> {
+    /// The binding factor and Lagrange coefficient of each participant.
+    factors: HashMap<Identifier<C>, (Scalar<C>, Scalar<C>)>,
+    gamma: Element<C>,
+    challenge: Scalar<C>,
+}
+
+impl<C> Transcript<C>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+{
+    fn new(
+        verifying_key: &VerifyingKey<C>,
+        H: &Element<C>,
+        commitments: &BTreeMap<Identifier<C>, round1::Commitments<C>>,
+    ) -> Result<Self, Error<C>> {
+        let mut preimage = verifying_key.serialize().to_vec();
+        preimage.extend_from_slice(&BabyJubJubGroup::serialize(H));
+        for (identifier, commitment) in commitments {
+            preimage.extend_from_slice(identifier.serialize().as_ref());
+            for element in [
+                &commitment.gamma,
+                &commitment.hiding,
+                &commitment.binding,
+                &commitment.hiding_h,
+                &commitment.binding_h,
+            ] {
+                preimage.extend_from_slice(&BabyJubJubGroup::serialize(element));
+            }
+        }
+
+        let domain = C::CONTEXT_STRING.to_owned() + "vrf-rho";
+        let identifiers: BTreeSet<_> = commitments.keys().copied().collect();
+        let mut factors = HashMap::new();
+        let (mut gamma, mut A, mut B) = (
+            BabyJubJubGroup::identity(),
+            BabyJubJubGroup::identity(),
+            BabyJubJubGroup::identity(),
+        );
+        for (identifier, commitment) in commitments {
+            let mut rho_preimage = preimage.clone();
+            rho_preimage.extend_from_slice(identifier.serialize().as_ref());
+            let rho = C::hash_to_scalar(domain.as_bytes(), &rho_preimage);
+            let lambda = compute_lagrange_coefficient(&identifiers, None, *identifier)?;
+            gamma += commitment.gamma * lambda;
+            A += commitment.hiding + commitment.binding * rho;
+            B += commitment.hiding_h + commitment.binding_h * rho;
+            factors.insert(*identifier, (rho, lambda));
+        }
+        let challenge =
+            dleq::challenge::<C>(LABEL, [H, &verifying_key.to_element(), &gamma, &A, &B]);
+        Ok(Self {
+            factors,
+            gamma,
+            challenge,
+        })
+    }
+}
+
+/// Checks the [`round2::Response`]s against `pubkeys` and combines them into
+/// the VRF [`Proof`] of `input` for the group verifying key.
+///
+/// Every participant with commitments must send a response, and there must
+/// be at least `min_signers` of them. Fails with
+/// [`CombineError::InvalidShares`] naming every participant whose partial
+/// evaluation or response is invalid or missing.
+pub fn combine<C>(
+    input: &[u8],
+    commitments: &BTreeMap<Identifier<C>, round1::Commitments<C>>,
+    responses: &HashMap<Identifier<C>, round2::Response<C>>,
+    pubkeys: &PublicKeyPackage<C>,
+    min_signers: u16,
+) -> Result<Proof<C>, CombineError<C>>
+where
+    C: Ciphersuite<Group = BabyJubJubGroup> + BabyJubJubHash,
+{
+    if commitments.len() < min_signers as usize {
+        return Err(Error::IncorrectNumberOfCommitments.into());
+    }
+
+    let H = hash_to_curve(pubkeys.group_public(), input)?;
+    let transcript = Transcript::new(pubkeys.group_public(), &H, commitments)?;
+    let G = BabyJubJubGroup::generator();
+    let mut invalid: BTreeSet<_> = responses
+        .keys()
+        .filter(|identifier| !commitments.contains_key(identifier))
+        .copied()
+        .collect();
+    let mut response = <<C::Group as Group>::Field>::zero();
+    for (identifier, commitment) in commitments {
+        let (rho, lambda) = transcript.factors[identifier];
+        let c = transcript.challenge * lambda;
+        let valid = match (
+            pubkeys.signer_pubkeys().get(identifier),
+            responses.get(identifier),
+        ) {
+            (Some(verifying_share), Some(share)) => {
+                let z = share.response;
+                G * z
+                    == commitment.hiding
+                        + commitment.binding * rho
+                        + verifying_share.to_element() * c
+                    && H * z
+                        == commitment.hiding_h + commitment.binding_h * rho + commitment.gamma * c
+            }
+            _ => false,
+        };
+        if !valid {
+            invalid.insert(*identifier);
+            continue;
+        }
+        response += responses[identifier].response;
+    }
+    if !invalid.is_empty() {
+        return Err(CombineError::InvalidShares(invalid));
+    }
+
+    Ok(Proof::new(
+        transcript.gamma,
+        dleq::Proof::new(transcript.challenge, response),
+    ))
+}
@@ -72,3 +72,13 @@ fn check_round2_package_common_traits() {
     let round2_package = samples::round2_package();
     check_common_traits_for_type(round2_package);
 }
+
+#[test]
+#[allow(clippy::clone_on_copy)]
+fn check_randomizer_common_traits() {
+    // `Randomizer` does not implement `Debug`, so only Clone, Copy and Eq are
+    // checked.
+    let randomizer = samples::randomizer();
+    let copied = randomizer;
+    assert!(randomizer == copied.clone());
+}
@@ -0,0 +1,85 @@
+use std::collections::{BTreeMap, HashMap};
+
+use frost_bjj::{BabyJubJubHash, BabyJubJubKeccak256, Fr};
+use rand::thread_rng;
+
+/// Keccak-256 with an application-specific context string.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AppCiphersuite;
+
+impl BabyJubJubHash for AppCiphersuite {
+    const ID: &'static str = "FROST(babyjubjub, Keccak-256, app)";
+
+    const CONTEXT_STRING: &'static str = "app-FROST-babyjubjub-KECCAK256-v1";
+
+    fn hash_to_scalar(domain: &[u8], msg: &[u8]) -> Fr {
+        BabyJubJubKeccak256::hash_to_scalar(domain, msg)
+    }
+
+    fn hash_to_array(domain: &[u8], msg: &[u8]) -> [u8; 32] {
+        BabyJubJubKeccak256::hash_to_array(domain, msg)
+    }
+}
+
+frost_bjj::babyjubjub_ciphersuite!(AppCiphersuite);
+
+mod app {
+    frost_bjj::frost_api!(super::AppCiphersuite, "FROST(babyjubjub, Keccak-256, app)");
+}
+
+#[test]
+fn check_sign_with_dealer() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_dealer::<AppCiphersuite, _>(rng);
+}
+
+#[test]
+fn check_sign_with_dkg() {
+    let rng = thread_rng();
+
+    frost_core::tests::ciphersuite_generic::check_sign_with_dkg::<AppCiphersuite, _>(rng);
+}
+
+#[test]
+fn check_generated_api() {
+    let mut rng = thread_rng();
+    let message = b"message to sign";
+
+    let (shares, pubkeys) =
+        app::keys::generate_with_dealer(3, 2, app::keys::IdentifierList::Default, &mut rng)
+            .unwrap();
+    let key_packages: HashMap<app::Identifier, app::keys::KeyPackage> = shares
+        .into_iter()
+        .take(2)
+        .map(|(id, share)| (id, share.try_into().unwrap()))
+        .collect();
+
+    let mut nonces = HashMap::new();
+    let mut commitments = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonce, commitment) = app::round1::commit(key_package.secret_share(), &mut rng);
+        nonces.insert(*id, nonce);
+        commitments.insert(*id, commitment);
+    }
+
+    let signing_package = app::SigningPackage::new(commitments, message);
+    let signature_shares = key_packages
+        .iter()
+        .map(|(id, key_package)| {
+            let share = app::round2::sign(&signing_package, &nonces[id], key_package).unwrap();
+            (*id, share)
+        })
+        .collect();
+    let signature = app::aggregate(&signing_package, &signature_shares, &pubkeys).unwrap();
+
+    assert!(pubkeys.group_public().verify(message, &signature).is_ok());
+
+    // The context string separates the suite from FROST(babyjubjub, Keccak-256).
+    let keccak_key =
+        frost_bjj::keccak256::VerifyingKey::deserialize(pubkeys.group_public().serialize())
+            .unwrap();
+    let keccak_signature =
+        frost_bjj::keccak256::Signature::deserialize(signature.serialize()).unwrap();
+    assert!(keccak_key.verify(message, &keccak_signature).is_err());
+}
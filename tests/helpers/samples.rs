@@ -2,14 +2,13 @@
 
 use std::collections::{BTreeMap, HashMap};
 
-use ark_ed_on_bn254::Fr;
-use ark_ff::{MontFp, PrimeField};
 use frost_bjj::{
     keys::{
         dkg::{round1, round2},
         KeyPackage, PublicKeyPackage, SecretShare, SigningShare, VerifiableSecretSharingCommitment,
         VerifyingShare,
     },
+    rerandomized::Randomizer,
     round1::{NonceCommitment, SigningCommitments},
     round2::SignatureShare,
     Ciphersuite, Field, Group, Signature, SigningPackage, VerifyingKey,
@@ -67,6 +66,11 @@ pub fn signature_share() -> SignatureShare {
     SignatureShare::deserialize(serialized_scalar).unwrap()
 }
 
+/// Generate a sample Randomizer.
+pub fn randomizer() -> Randomizer {
+    Randomizer::from_scalar(scalar1())
+}
+
 /// Generate a sample SecretShare.
 pub fn secret_share() -> SecretShare {
     let identifier = 42u16.try_into().unwrap();
@@ -6,6 +6,7 @@ use frost_bjj::{
         dkg::{round1, round2},
         KeyPackage, PublicKeyPackage, SecretShare,
     },
+    rerandomized::Randomizer,
     round1::SigningCommitments,
     round2::SignatureShare,
     SigningPackage,
@@ -48,6 +49,17 @@ fn check_signature_share_recreation() {
     assert!(signature_share == new_signature_share);
 }
 
+/// Check if Randomizer can be recreated.
+#[test]
+fn check_randomizer_recreation() {
+    let randomizer = samples::randomizer();
+
+    let encoded = randomizer.serialize();
+
+    let new_randomizer = Randomizer::deserialize(&encoded).unwrap();
+    assert!(randomizer == new_randomizer);
+}
+
 /// Check if SecretShare can be recreated.
 #[test]
 fn check_secret_share_recreation() {
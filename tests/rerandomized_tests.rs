@@ -1,4 +1,7 @@
-use frost_bjj::BabyJubJubSha256;
+use std::collections::{BTreeMap, HashMap};
+
+use frost_bjj::rerandomized::{self, RandomizedParams};
+use frost_bjj::{BabyJubJubKeccak256, BabyJubJubPoseidon, BabyJubJubSha256, JubjubSha256};
 use rand::thread_rng;
 
 #[test]
@@ -8,3 +11,89 @@ fn check_randomized_sign_with_dealer() {
     let (_msg, _group_signature, _group_pubkey) =
         frost_rerandomized::tests::check_randomized_sign_with_dealer::<BabyJubJubSha256, _>(rng);
 }
+
+#[test]
+fn check_randomized_sign_with_dealer_poseidon() {
+    let rng = thread_rng();
+
+    let (_msg, _group_signature, _group_pubkey) =
+        frost_rerandomized::tests::check_randomized_sign_with_dealer::<BabyJubJubPoseidon, _>(rng);
+}
+
+#[test]
+fn check_randomized_sign_with_dealer_keccak256() {
+    let rng = thread_rng();
+
+    let (_msg, _group_signature, _group_pubkey) =
+        frost_rerandomized::tests::check_randomized_sign_with_dealer::<BabyJubJubKeccak256, _>(rng);
+}
+
+#[test]
+fn check_randomized_sign_with_dealer_jubjub() {
+    let rng = thread_rng();
+
+    let (_msg, _group_signature, _group_pubkey) =
+        frost_rerandomized::tests::check_randomized_sign_with_dealer::<JubjubSha256, _>(rng);
+}
+
+#[test]
+fn check_rerandomized_api() {
+    let mut rng = thread_rng();
+    let message = b"message to sign";
+
+    let (shares, pubkeys) = frost_bjj::keys::generate_with_dealer(
+        5,
+        3,
+        frost_bjj::keys::IdentifierList::Default,
+        &mut rng,
+    )
+    .unwrap();
+    let key_packages: HashMap<_, _> = shares
+        .into_iter()
+        .take(3)
+        .map(|(id, share)| (id, frost_bjj::keys::KeyPackage::try_from(share).unwrap()))
+        .collect();
+
+    let mut nonces = HashMap::new();
+    let mut commitments = BTreeMap::new();
+    for (id, key_package) in &key_packages {
+        let (nonce, commitment) = frost_bjj::round1::commit(key_package.secret_share(), &mut rng);
+        nonces.insert(*id, nonce);
+        commitments.insert(*id, commitment);
+    }
+    let signing_package = frost_bjj::SigningPackage::new(commitments, message);
+
+    let randomized_params = RandomizedParams::new(pubkeys.group_public(), &mut rng);
+    let signature_shares: HashMap<_, _> = key_packages
+        .iter()
+        .map(|(id, key_package)| {
+            let share = rerandomized::sign(
+                &signing_package,
+                &nonces[id],
+                key_package,
+                *randomized_params.randomizer(),
+            )
+            .unwrap();
+            (*id, share)
+        })
+        .collect();
+    let signature = rerandomized::aggregate(
+        &signing_package,
+        &signature_shares,
+        &pubkeys,
+        &randomized_params,
+    )
+    .unwrap();
+
+    let randomized_verifying_key = randomized_params.randomized_verifying_key();
+    assert!(randomized_verifying_key.verify(message, &signature).is_ok());
+    assert!(pubkeys.group_public().verify(message, &signature).is_err());
+    assert_ne!(randomized_verifying_key, pubkeys.group_public());
+
+    // Shares signed with another randomizer are rejected.
+    let other_params = RandomizedParams::new(pubkeys.group_public(), &mut rng);
+    assert!(
+        rerandomized::aggregate(&signing_package, &signature_shares, &pubkeys, &other_params)
+            .is_err()
+    );
+}
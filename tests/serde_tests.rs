@@ -7,6 +7,7 @@ use frost_bjj::{
         dkg::{round1, round2},
         KeyPackage, PublicKeyPackage, SecretShare,
     },
+    rerandomized::Randomizer,
     round1::SigningCommitments,
     round2::SignatureShare,
     SigningPackage,
@@ -200,6 +201,25 @@ fn check_signature_share_serialization() {
     assert!(serde_json::from_str::<SignatureShare>(invalid_json).is_err());
 }
 
+#[test]
+fn check_randomizer_serialization() {
+    let randomizer = samples::randomizer();
+
+    let json = serde_json::to_string_pretty(&randomizer).unwrap();
+    println!("{}", json);
+
+    let decoded_randomizer: Randomizer = serde_json::from_str(&json).unwrap();
+    assert!(randomizer == decoded_randomizer);
+
+    let json = r#""a1c4c0d092baa1ef06f41526d0f32972b21c20e079b0067a037819e8de5b0804""#;
+    let decoded_randomizer: Randomizer = serde_json::from_str(json).unwrap();
+    assert!(randomizer == decoded_randomizer);
+
+    // Wrong length
+    let invalid_json = r#""a1c4c0d092baa1ef06f41526d0f32972b21c20e079b0067a037819e8de5b08""#;
+    assert!(serde_json::from_str::<Randomizer>(invalid_json).is_err());
+}
+
 #[test]
 fn check_secret_share_serialization() {
     let secret_share = samples::secret_share();
@@ -7,13 +7,13 @@ use frost_bjj::{
         dkg::{round1, round2},
         KeyPackage, PublicKeyPackage, SecretShare,
     },
+    rerandomized::Randomizer,
     round1::SigningCommitments,
     round2::SignatureShare,
     SigningPackage,
 };
 
 use helpers::samples;
-use insta::assert_snapshot;
 
 #[test]
 fn check_signing_commitments_postcard_serialization() {
@@ -44,6 +44,15 @@ fn check_signature_share_postcard_serialization() {
     // assert_snapshot!(hex::encode(bytes));
     assert_eq!(signature_share, SignatureShare::deserialize(bytes).unwrap());
 }
+
+#[test]
+fn check_randomizer_postcard_serialization() {
+    let randomizer = samples::randomizer();
+    let bytes = randomizer.serialize();
+    // assert_snapshot!(hex::encode(bytes));
+    assert!(randomizer == Randomizer::deserialize(&bytes).unwrap());
+}
+
 #[test]
 fn check_secret_share_postcard_serialization() {
     let secret_share = samples::secret_share();